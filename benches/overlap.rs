@@ -0,0 +1,43 @@
+//! Benchmarks for calculating the overlap between many hotspots.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use hotspots::{Coordinate, CoordinateValue, Hotspot};
+
+/// Generate `count` hotspots spread across the coordinate space, using a
+/// simple linear congruential generator so runs are reproducible.
+fn hotspots(count: usize) -> Vec<Hotspot> {
+    let mut state: u32 = 0x2545_f491;
+    let mut next = move || {
+        state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        (state >> 16) as CoordinateValue
+    };
+
+    (0..count)
+        .map(|_| {
+            let (x, y) = (next() % 4000, next() % 4000);
+            let (w, h) = (next() % 500, next() % 500);
+            Hotspot::builder().from_pixels((Coordinate { x, y }, Coordinate { x: x + w, y: y + h }))
+        })
+        .collect()
+}
+
+fn bench_overlap(c: &mut Criterion) {
+    let hotspots = hotspots(1000);
+
+    c.bench_function("overlap 1000x1000", |b| {
+        b.iter(|| {
+            let mut total = 0.0;
+            for a in &hotspots {
+                for other in &hotspots {
+                    total += black_box(a).overlap(black_box(other));
+                }
+            }
+            total
+        });
+    });
+}
+
+criterion_group!(benches, bench_overlap);
+criterion_main!(benches);
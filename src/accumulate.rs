@@ -0,0 +1,227 @@
+//! Accumulators for averaging many coordinates or hotspots.
+//!
+//! Summing coordinates directly in [`CoordinateValue`] overflows quickly, so
+//! these accumulate in a `u128` instead, along with a `u64` count of the
+//! items added. Summing up to 2^96 coordinates of `CoordinateValue::MAX`
+//! can't overflow the `u128` sums, so the limit is the count, which allows up
+//! to `u64::MAX` coordinates.
+
+use core::marker::PhantomData;
+
+use crate::{Coordinate, CoordinateValue, Hotspot, repr::PixelRepr};
+
+/// Sums coordinates so their mean can be calculated without overflow.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub struct CoordinateAccumulator {
+    x: u128,
+    y: u128,
+    count: u64,
+}
+
+impl CoordinateAccumulator {
+    /// Create an empty accumulator.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            x: 0,
+            y: 0,
+            count: 0,
+        }
+    }
+
+    /// Add a coordinate to the running sums.
+    #[inline]
+    pub const fn add(&mut self, coordinate: Coordinate) {
+        self.x += coordinate.x as u128;
+        self.y += coordinate.y as u128;
+        self.count += 1;
+    }
+
+    /// The number of coordinates which have been added.
+    #[inline]
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Calculate the mean of the coordinates which were added, rounded to the
+    /// closest coordinate.
+    ///
+    /// Returns `None` if no coordinates were added.
+    #[inline]
+    pub const fn finish(&self) -> Option<Coordinate> {
+        if self.count == 0 {
+            return None;
+        }
+
+        // The mean of values which are each at most `CoordinateValue::MAX` is
+        // itself at most `CoordinateValue::MAX`, so these casts are lossless.
+        let count = self.count as u128;
+        Some(Coordinate {
+            x: ((self.x + count / 2) / count) as CoordinateValue,
+            y: ((self.y + count / 2) / count) as CoordinateValue,
+        })
+    }
+}
+
+impl Extend<Coordinate> for CoordinateAccumulator {
+    fn extend<I: IntoIterator<Item = Coordinate>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|coordinate| self.add(coordinate));
+    }
+}
+
+impl core::iter::Sum<Coordinate> for CoordinateAccumulator {
+    fn sum<I: Iterator<Item = Coordinate>>(iter: I) -> Self {
+        let mut accumulator = Self::new();
+        accumulator.extend(iter);
+        accumulator
+    }
+}
+
+/// Sums the corners of hotspots so their mean hotspot can be calculated
+/// without overflow.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct HotspotAccumulator<R = PixelRepr> {
+    upper_right: CoordinateAccumulator,
+    lower_left: CoordinateAccumulator,
+    _repr: PhantomData<R>,
+}
+
+impl<R> Default for HotspotAccumulator<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R> HotspotAccumulator<R> {
+    /// Create an empty accumulator.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            upper_right: CoordinateAccumulator::new(),
+            lower_left: CoordinateAccumulator::new(),
+            _repr: PhantomData,
+        }
+    }
+
+    /// Add the corners of a hotspot to the running sums.
+    #[inline]
+    pub const fn add(&mut self, hotspot: &Hotspot<R>) {
+        self.upper_right.add(hotspot.upper_right);
+        self.lower_left.add(hotspot.lower_left);
+    }
+
+    /// The number of hotspots which have been added.
+    #[inline]
+    pub const fn count(&self) -> u64 {
+        self.upper_right.count()
+    }
+
+    /// Calculate the hotspot whose corners are the mean of the corners of the
+    /// hotspots which were added, rounded to the closest coordinate.
+    ///
+    /// Returns `None` if no hotspots were added.
+    #[inline]
+    pub const fn finish(&self) -> Option<Hotspot<R>> {
+        match (self.upper_right.finish(), self.lower_left.finish()) {
+            (Some(upper_right), Some(lower_left)) => Some(Hotspot {
+                upper_right,
+                lower_left,
+                _repr: PhantomData,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl<R> Extend<Hotspot<R>> for HotspotAccumulator<R> {
+    fn extend<I: IntoIterator<Item = Hotspot<R>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|hotspot| self.add(&hotspot));
+    }
+}
+
+impl<R> core::iter::Sum<Hotspot<R>> for HotspotAccumulator<R> {
+    fn sum<I: Iterator<Item = Hotspot<R>>>(iter: I) -> Self {
+        let mut accumulator = Self::new();
+        accumulator.extend(iter);
+        accumulator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coordinate_accumulator_mean() {
+        let mut accumulator = CoordinateAccumulator::new();
+        accumulator.add(Coordinate { x: 0, y: 10 });
+        accumulator.add(Coordinate { x: 10, y: 20 });
+        accumulator.add(Coordinate { x: 11, y: 30 });
+
+        // 21 / 3 = 7, 60 / 3 = 20
+        assert_eq!(accumulator.count(), 3);
+        assert_eq!(accumulator.finish(), Some(Coordinate { x: 7, y: 20 }));
+
+        // 22 / 4 = 5.5 rounds up, 60 / 4 = 15
+        accumulator.add(Coordinate { x: 1, y: 0 });
+        assert_eq!(accumulator.finish(), Some(Coordinate { x: 6, y: 15 }));
+
+        assert_eq!(CoordinateAccumulator::new().finish(), None);
+    }
+
+    #[test]
+    fn test_coordinate_accumulator_near_max_without_overflow() {
+        let near_max = |i: CoordinateValue| Coordinate {
+            x: CoordinateValue::MAX - i % 2,
+            y: CoordinateValue::MAX - i % 3,
+        };
+
+        let accumulator: CoordinateAccumulator = (0..1000).map(near_max).sum();
+
+        // x alternates between MAX and MAX - 1 so the mean of MAX - 0.5 rounds
+        // up, y cycles MAX, MAX - 1, MAX - 2 so the mean is just above MAX - 1
+        assert_eq!(
+            accumulator.finish(),
+            Some(Coordinate {
+                x: CoordinateValue::MAX,
+                y: CoordinateValue::MAX - 1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_coordinate_accumulator_beyond_internal_calculation_type() {
+        // Enough coordinates at the maximum value to overflow a u32 sum even
+        // without `high_precision`
+        let count = 100_000;
+        let accumulator: CoordinateAccumulator = core::iter::repeat_n(Coordinate::MAX, count).sum();
+
+        assert_eq!(accumulator.count(), count as u64);
+        assert_eq!(accumulator.finish(), Some(Coordinate::MAX));
+    }
+
+    #[test]
+    fn test_hotspot_accumulator_mean() {
+        let hotspots = [
+            Hotspot::builder()
+                .from_pixels((Coordinate { x: 0, y: 0 }, Coordinate { x: 10, y: 20 })),
+            Hotspot::builder()
+                .from_pixels((Coordinate { x: 4, y: 2 }, Coordinate { x: 20, y: 30 })),
+        ];
+
+        let mut accumulator = HotspotAccumulator::new();
+        accumulator.extend(hotspots);
+        assert_eq!(accumulator.count(), 2);
+        assert_eq!(
+            accumulator.finish(),
+            Some(
+                Hotspot::builder()
+                    .from_pixels((Coordinate { x: 2, y: 1 }, Coordinate { x: 15, y: 25 }))
+            )
+        );
+
+        let summed: HotspotAccumulator = hotspots.into_iter().sum();
+        assert_eq!(summed, accumulator);
+        assert_eq!(HotspotAccumulator::<PixelRepr>::new().finish(), None);
+    }
+}
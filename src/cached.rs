@@ -0,0 +1,108 @@
+//! Hotspots with their area cached, for comparing the same hotspots many
+//! times.
+//!
+//! [`Hotspot::overlap`] and [`Hotspot::overlap_in`] calculate the area of each
+//! hotspot on every call. When one hotspot is compared against thousands of
+//! others, repeatedly, [`CachedHotspot`] calculates it once up front instead.
+
+use crate::{Hotspot, InternalCalculationType, repr::PixelRepr};
+
+/// A pixel hotspot along with its precomputed [`Hotspot::area`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct CachedHotspot {
+    hotspot: Hotspot<PixelRepr>,
+    area: InternalCalculationType,
+}
+
+impl CachedHotspot {
+    /// Wrap a hotspot, calculating its area.
+    #[inline]
+    pub const fn new(hotspot: Hotspot<PixelRepr>) -> Self {
+        Self {
+            hotspot,
+            area: hotspot.area(),
+        }
+    }
+
+    /// Get the wrapped hotspot.
+    #[inline]
+    pub const fn hotspot(&self) -> &Hotspot<PixelRepr> {
+        &self.hotspot
+    }
+
+    /// Get the cached area of the wrapped hotspot.
+    #[inline]
+    pub const fn area(&self) -> InternalCalculationType {
+        self.area
+    }
+
+    /// Calculate the [`Hotspot::overlap`] between two cached hotspots, using
+    /// both cached areas.
+    #[inline]
+    pub const fn overlap(&self, other: &Self) -> f32 {
+        self.hotspot
+            .overlap_with_areas(&other.hotspot, self.area, other.area)
+    }
+
+    /// Calculate the [`Hotspot::overlap_in`] of this hotspot within `other`,
+    /// using the cached area of this hotspot.
+    #[inline]
+    pub const fn overlap_in(&self, other: &Hotspot<PixelRepr>) -> f32 {
+        self.hotspot.overlap_in_with_area(other, self.area)
+    }
+}
+
+impl From<Hotspot<PixelRepr>> for CachedHotspot {
+    fn from(hotspot: Hotspot<PixelRepr>) -> Self {
+        Self::new(hotspot)
+    }
+}
+
+impl From<CachedHotspot> for Hotspot<PixelRepr> {
+    fn from(cached: CachedHotspot) -> Self {
+        cached.hotspot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Coordinate, CoordinateValue};
+
+    fn make_hotspot(x1: u16, y1: u16, x2: u16, y2: u16) -> Hotspot<PixelRepr> {
+        Hotspot::builder().from_pixels((
+            Coordinate::new(x1 as CoordinateValue, y1 as CoordinateValue),
+            Coordinate::new(x2 as CoordinateValue, y2 as CoordinateValue),
+        ))
+    }
+
+    #[test]
+    fn test_cached_matches_hotspot() {
+        let hotspots = [
+            make_hotspot(0, 0, 10, 10),
+            make_hotspot(5, 0, 15, 10),
+            make_hotspot(2, 2, 4, 4),
+            make_hotspot(10, 0, 20, 10),
+            make_hotspot(5, 5, 5, 5),
+            make_hotspot(0, 0, u16::MAX, u16::MAX),
+        ];
+
+        for a in hotspots {
+            let cached = CachedHotspot::new(a);
+            assert_eq!(cached.area(), a.area());
+            assert_eq!(cached.hotspot(), &a);
+
+            for b in hotspots {
+                assert_eq!(cached.overlap(&b.into()).to_bits(), a.overlap(&b).to_bits());
+                assert_eq!(cached.overlap_in(&b).to_bits(), a.overlap_in(&b).to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn test_cached_round_trip() {
+        let hotspot = make_hotspot(1, 2, 3, 4);
+        let cached: CachedHotspot = hotspot.into();
+        assert_eq!(Hotspot::from(cached), hotspot);
+    }
+}
@@ -0,0 +1,73 @@
+//! Floating point helpers.
+//!
+//! `core` doesn't provide functions such as `sqrt` and `round` for floats, so
+//! these use the built-in methods when the `std` feature is enabled and fall
+//! back to `libm` otherwise.
+
+/// Round to the closest integer, rounding half-way cases away from zero.
+#[inline]
+pub(crate) fn round(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        f64::round(x)
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        libm::round(x)
+    }
+}
+
+/// Calculate the square root.
+#[inline]
+pub(crate) fn sqrt(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        f64::sqrt(x)
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        libm::sqrt(x)
+    }
+}
+
+/// Calculate the arccosine, in radians.
+#[inline]
+pub(crate) fn acos(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        f64::acos(x)
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        libm::acos(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round() {
+        assert_eq!(round(2.4), 2.0);
+        assert_eq!(round(2.5), 3.0);
+        assert_eq!(round(-2.5), -3.0);
+    }
+
+    #[test]
+    fn test_sqrt() {
+        assert_eq!(sqrt(25.0), 5.0);
+        assert_eq!(sqrt(0.0), 0.0);
+        assert!(sqrt(-1.0).is_nan());
+    }
+
+    #[test]
+    fn test_acos() {
+        assert_eq!(acos(1.0), 0.0);
+        assert!((acos(0.0) - core::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        assert!((acos(-1.0) - core::f64::consts::PI).abs() < 1e-12);
+    }
+}
@@ -5,6 +5,17 @@
 // - Introduce utilites to make conversions between different origins easier.
 // - Make the repr module almost fully internal and provide simple exported types - similar to the MPN crate.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+mod float;
+
+pub mod accumulate;
+pub mod cached;
 pub mod repr;
 
 #[cfg(feature = "serde")]
@@ -20,11 +31,12 @@ use repr::*;
 /// Coordinate type definition.
 ///
 /// Coordinates are stored as fractions of `CoordinateValue::MAX`. The u16 type
-/// provides 65,536 discrete positions. For percentage-based hotspots, precision
-/// loss occurs when image dimensions exceed this value (expect ~1-2 pixel
-/// rounding on 100,000px images).
+/// provides 65,536 discrete positions, and image dimensions are also stored as
+/// a `CoordinateValue`, so images can be at most 65,535 pixels along each
+/// axis. Within that limit converting a pixel hotspot to a percentage hotspot
+/// and back with the same dimensions is exact, see [`Hotspot::as_percentage`].
 ///
-/// Enable the "high_precision" feature for images larger than ~65,000 pixels.
+/// Enable the "high_precision" feature for images larger than 65,535 pixels.
 #[cfg(not(feature = "high_precision"))]
 pub type CoordinateValue = u16;
 
@@ -36,17 +48,17 @@ pub type CoordinateValue = u16;
 #[cfg(feature = "high_precision")]
 pub type CoordinateValue = u32;
 
-/// An internal type used for the result from multiplication between two
-/// [`CoordinateValue`] to ensure no loss of precision.
+/// The type used for the result from multiplication between two
+/// [`CoordinateValue`] to ensure no loss of precision, e.g. when calculating
+/// the area of a hotspot.
 #[cfg(not(feature = "high_precision"))]
-#[doc(hidden)]
-type InternalCalculationType = u32;
+pub type InternalCalculationType = u32;
 
-/// An internal type used for the result from multiplication between two
-/// [`CoordinateValue`] to ensure no loss of precision.
+/// The type used for the result from multiplication between two
+/// [`CoordinateValue`] to ensure no loss of precision, e.g. when calculating
+/// the area of a hotspot.
 #[cfg(feature = "high_precision")]
-#[doc(hidden)]
-type InternalCalculationType = u64;
+pub type InternalCalculationType = u64;
 
 /// A function which rounds two numbers to the closest value using integer divison.
 #[inline]
@@ -75,6 +87,138 @@ macro_rules! max {
     ($a:expr, $b:expr) => {{ if $a > $b { $a } else { $b } }};
 }
 
+/// Returns the provided hotspot, first checking that its upper-right corner
+/// isn't below or left of its lower-left corner when the `debug_check` feature
+/// is enabled.
+///
+/// Transforms which build corners by hand should wrap their result in this, so
+/// a bug which inverts the corners panics naming the transform `$op` instead of
+/// silently producing a broken hotspot. Without the feature this is a no-op.
+macro_rules! debug_check {
+    ($hotspot:expr, $op:literal) => {{
+        let hotspot = $hotspot;
+        #[cfg(feature = "debug_check")]
+        if hotspot.upper_right.x < hotspot.lower_left.x
+            || hotspot.upper_right.y < hotspot.lower_left.y
+        {
+            panic!(concat!(
+                "`",
+                $op,
+                "` produced a hotspot with inverted corners"
+            ));
+        }
+        hotspot
+    }};
+}
+
+/// Errors which can occur when constructing or parsing hotspots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HotspotError {
+    /// The input did not contain the expected number of fields.
+    InvalidFieldCount { expected: usize, found: usize },
+    /// A field could not be parsed as a [`CoordinateValue`].
+    InvalidCoordinate(core::num::ParseIntError),
+}
+
+impl core::fmt::Display for HotspotError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidFieldCount { expected, found } => {
+                write!(f, "expected {expected} fields but found {found}")
+            }
+            Self::InvalidCoordinate(e) => write!(f, "invalid coordinate: {e}"),
+        }
+    }
+}
+
+impl core::error::Error for HotspotError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::InvalidFieldCount { .. } => None,
+            Self::InvalidCoordinate(e) => Some(e),
+        }
+    }
+}
+
+/// Errors which can occur when constructing a [`Coordinate`].
+///
+/// Every [`CoordinateValue`] is currently a valid coordinate value, so this
+/// has no variants and [`Coordinate::try_new`] can't fail. It exists so that
+/// precision-specific limits can be enforced later without changing the
+/// signature of [`Coordinate::try_new`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CoordinateError {}
+
+impl core::fmt::Display for CoordinateError {
+    fn fmt(&self, _f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {}
+    }
+}
+
+impl core::error::Error for CoordinateError {}
+
+/// A sensible default threshold for [`Hotspot::should_merge`].
+///
+/// Two hotspots are treated as the same detection once at least half of either
+/// one lies within the other. This is based on [`Hotspot::max_overlap`] rather
+/// than [`Hotspot::overlap`], see the documentation on [`Hotspot::overlap`] for
+/// why the latter under-reports overlap for hotspots of very different sizes.
+/// Tune this for your own data.
+pub const DEFAULT_MERGE_IOU: f32 = 0.5;
+
+/// Fit a span of `len` centered on `lo..=hi` within `min..=max`.
+///
+/// The span is shifted to stay within the bounds where possible, if `len` is
+/// larger than the bounds the span is clamped to the bounds instead.
+const fn fit_span(
+    lo: CoordinateValue,
+    hi: CoordinateValue,
+    len: CoordinateValue,
+    min: CoordinateValue,
+    max: CoordinateValue,
+) -> (CoordinateValue, CoordinateValue) {
+    if len >= max - min {
+        return (min, max);
+    }
+
+    // Work in doubled units so that the center doesn't need to be rounded.
+    let doubled_start = (lo as InternalCalculationType + hi as InternalCalculationType)
+        .saturating_sub(len as InternalCalculationType);
+    let start = (doubled_start / 2) as CoordinateValue;
+    let start = max!(start, min);
+    let start = min!(start, max - len);
+
+    (start, start + len)
+}
+
+/// Multiply `value` by `factor`, rounding to the closest value and clamping to
+/// `0..=bound`.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn scale_value(value: CoordinateValue, factor: f64, bound: CoordinateValue) -> CoordinateValue {
+    // Float to int casts saturate, so negative values (and NaN) become 0.
+    let scaled = float::round(value as f64 * factor) as CoordinateValue;
+    min!(scaled, bound)
+}
+
+/// Convert a ratio between `0.0` and `1.0` to a fraction of
+/// [`CoordinateValue::MAX`], clamping it into range and rounding to the
+/// closest value.
+const fn ratio_to_value(ratio: f32) -> CoordinateValue {
+    // Float to int casts saturate, so NaN becomes 0.
+    (ratio.clamp(0.0, 1.0) as f64 * CoordinateValue::MAX as f64 + 0.5) as CoordinateValue
+}
+
+/// Divide two areas to get a ratio between 0 and 1.
+///
+/// The division is done in f64 so large areas (especially under
+/// `high_precision`) don't lose precision before being cast down, and the
+/// result is clamped so rounding can never produce a value outside `[0, 1]`.
+#[inline]
+const fn unit_ratio(numerator: f64, denominator: f64) -> f32 {
+    (numerator / denominator).clamp(0.0, 1.0) as f32
+}
+
 /// A coordinate in 2 Dimensional space.
 ///
 /// The coordinates contained in this struct are always non-negative and bounded
@@ -96,6 +240,102 @@ pub struct Coordinate {
     pub y: CoordinateValue,
 }
 
+impl Coordinate {
+    /// The origin, `(0, 0)`.
+    pub const ORIGIN: Self = Self { x: 0, y: 0 };
+
+    /// The largest representable coordinate, `(CoordinateValue::MAX, CoordinateValue::MAX)`.
+    pub const MAX: Self = Self {
+        x: CoordinateValue::MAX,
+        y: CoordinateValue::MAX,
+    };
+
+    /// Create a new coordinate.
+    #[inline]
+    pub const fn new(x: CoordinateValue, y: CoordinateValue) -> Self {
+        Self { x, y }
+    }
+
+    /// Create a new coordinate, returning an error if either value is outside
+    /// of the representable range.
+    ///
+    /// The representable range is `0..=65_535` by default, or
+    /// `0..=4_294_967_295` with the `high_precision` feature. As this is the
+    /// full range of [`CoordinateValue`] this currently always returns `Ok`,
+    /// see [`CoordinateError`].
+    #[inline]
+    pub const fn try_new(x: CoordinateValue, y: CoordinateValue) -> Result<Self, CoordinateError> {
+        Ok(Self::new(x, y))
+    }
+
+    /// Create a new coordinate, returning `None` if either value is outside of
+    /// the representable range. See [`Coordinate::try_new`].
+    #[inline]
+    pub const fn checked_new(x: CoordinateValue, y: CoordinateValue) -> Option<Self> {
+        match Self::try_new(x, y) {
+            Ok(coordinate) => Some(coordinate),
+            Err(_) => None,
+        }
+    }
+
+    /// Get the horizontal position of this coordinate.
+    #[inline]
+    pub const fn x(&self) -> CoordinateValue {
+        self.x
+    }
+
+    /// Get the vertical position of this coordinate.
+    #[inline]
+    pub const fn y(&self) -> CoordinateValue {
+        self.y
+    }
+
+    /// Calculate the straight-line (Euclidean) distance between two coordinates.
+    ///
+    /// Each axis is squared as an [`InternalCalculationType`], so this can't
+    /// overflow even for coordinates at opposite corners of the coordinate
+    /// space.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn euclidean_distance(&self, other: &Self) -> f32 {
+        let dx = self.x.abs_diff(other.x) as InternalCalculationType;
+        let dy = self.y.abs_diff(other.y) as InternalCalculationType;
+        // Each square fits, but their sum may not.
+        float::sqrt((dx * dx) as f64 + (dy * dy) as f64) as f32
+    }
+
+    /// Calculate the Manhattan distance between two coordinates, i.e. the sum
+    /// of the distances along each axis.
+    #[inline]
+    pub const fn manhattan_distance(&self, other: &Self) -> InternalCalculationType {
+        self.x.abs_diff(other.x) as InternalCalculationType
+            + self.y.abs_diff(other.y) as InternalCalculationType
+    }
+}
+
+/// Convert an `(x, y)` tuple, e.g. `Coordinate::from((10, 20))`.
+impl From<(CoordinateValue, CoordinateValue)> for Coordinate {
+    fn from((x, y): (CoordinateValue, CoordinateValue)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+/// Convert to an `(x, y)` tuple.
+impl From<Coordinate> for (CoordinateValue, CoordinateValue) {
+    fn from(Coordinate { x, y }: Coordinate) -> Self {
+        (x, y)
+    }
+}
+
+/// Formats the coordinate as `x:y`, with each value zero-padded to the full
+/// width of a [`CoordinateValue`] in hex, e.g. `000a:0014`.
+impl core::fmt::LowerHex for Coordinate {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        const WIDTH: usize = core::mem::size_of::<CoordinateValue>() * 2;
+        write!(f, "{:0WIDTH$x}:{:0WIDTH$x}", self.x, self.y)
+    }
+}
+
 /// The dimensions of an image.
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ImageDimensions {
@@ -103,6 +343,21 @@ pub struct ImageDimensions {
     pub height: CoordinateValue,
 }
 
+/// Labels for the four corners of a [`Hotspot`], see
+/// [`Hotspot::labeled_corners`].
+///
+/// The labels follow the [`Coordinate`] axes, with x increasing to the right
+/// and y increasing upwards, so [`Corner::UpperLeft`] is `(min_x, max_y)`.
+/// They're named to match the corner accessors such as
+/// [`Hotspot::upper_left`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Corner {
+    LowerLeft,
+    LowerRight,
+    UpperRight,
+    UpperLeft,
+}
+
 /// A rectangular hotspot represented as two `Coordinate`s: upper-right and lower-left.
 ///
 /// The internal representation can be either pixel-based or percentage-based,
@@ -115,32 +370,171 @@ pub struct Hotspot<R = PixelRepr> {
 }
 
 impl Hotspot<PixelRepr> {
+    /// Create a hotspot covering the whole of an image with the provided
+    /// dimensions, e.g. for "select all".
+    #[inline]
+    pub const fn full(image_dimensions: ImageDimensions) -> Self {
+        Self {
+            upper_right: Coordinate {
+                x: image_dimensions.width,
+                y: image_dimensions.height,
+            },
+            lower_left: Coordinate::ORIGIN,
+            _repr: PhantomData,
+        }
+    }
+
+    /// Clamp this hotspot so it lies within an image with the provided
+    /// dimensions, returning the part of the hotspot inside the image.
+    ///
+    /// If the hotspot lies entirely outside the image this returns a
+    /// zero-area hotspot on the nearest edge of the image, or at the nearest
+    /// corner if the hotspot is outside the image on both axes.
+    #[inline]
+    pub const fn clamp_to(&self, image_dimensions: ImageDimensions) -> Self {
+        let ImageDimensions { width, height } = image_dimensions;
+        // Coordinates are unsigned, so are already clamped to the origin.
+        debug_check!(
+            Self {
+                upper_right: Coordinate {
+                    x: min!(self.upper_right.x, width),
+                    y: min!(self.upper_right.y, height),
+                },
+                lower_left: Coordinate {
+                    x: min!(self.lower_left.x, width),
+                    y: min!(self.lower_left.y, height),
+                },
+                _repr: PhantomData,
+            },
+            "Hotspot::clamp_to"
+        )
+    }
+
+    /// Snap each corner of this hotspot to the closest boundary of a grid
+    /// splitting an image with the provided dimensions into `cols` by `rows`
+    /// equal blocks, e.g. for tiling an image.
+    ///
+    /// The boundary between blocks `k - 1` and `k` is at `k * width / cols`
+    /// rounded to the closest pixel, so the grid always spans the whole image
+    /// even when the dimensions don't divide evenly. The hotspot is clamped to
+    /// the image first, and may snap down to zero size if it's smaller than a
+    /// block. A `cols` or `rows` of zero is treated as one.
+    #[inline]
+    pub const fn snap_to_image_grid(
+        &self,
+        cols: u32,
+        rows: u32,
+        image_dimensions: ImageDimensions,
+    ) -> Self {
+        const fn snap(
+            value: CoordinateValue,
+            blocks: u32,
+            size: CoordinateValue,
+        ) -> CoordinateValue {
+            if size == 0 {
+                return 0;
+            }
+            let (value, blocks, size) = (value as u64, max!(blocks, 1) as u64, size as u64);
+            let block = (value * blocks + size / 2) / size;
+            ((block * size + blocks / 2) / blocks) as CoordinateValue
+        }
+
+        let ImageDimensions { width, height } = image_dimensions;
+        let clamped = self.clamp_to(image_dimensions);
+        debug_check!(
+            Self {
+                upper_right: Coordinate {
+                    x: snap(clamped.upper_right.x, cols, width),
+                    y: snap(clamped.upper_right.y, rows, height),
+                },
+                lower_left: Coordinate {
+                    x: snap(clamped.lower_left.x, cols, width),
+                    y: snap(clamped.lower_left.y, rows, height),
+                },
+                _repr: PhantomData,
+            },
+            "Hotspot::snap_to_image_grid"
+        )
+    }
+
+    /// Get the upper-right corner, `(max_x, max_y)`.
     #[inline]
     pub const fn upper_right(&self) -> Coordinate {
         self.upper_right
     }
 
+    /// Get the upper-left corner, `(min_x, max_y)`.
     #[inline]
     pub const fn upper_left(&self) -> Coordinate {
         Coordinate {
-            x: self.upper_right.x,
-            y: self.lower_left.y,
+            x: self.lower_left.x,
+            y: self.upper_right.y,
         }
     }
 
+    /// Get the lower-left corner, `(min_x, min_y)`.
     #[inline]
     pub const fn lower_left(&self) -> Coordinate {
         self.lower_left
     }
 
+    /// Get the lower-right corner, `(max_x, min_y)`.
     #[inline]
     pub const fn lower_right(&self) -> Coordinate {
         Coordinate {
-            x: self.lower_left.x,
-            y: self.upper_right.y,
+            x: self.upper_right.x,
+            y: self.lower_left.y,
         }
     }
 
+    /// Get all four corners of this hotspot, ordered as
+    /// [`Hotspot::raw_corners`].
+    #[inline]
+    pub const fn corners(&self) -> [Coordinate; 4] {
+        self.raw_corners()
+    }
+
+    /// Get the four [`Hotspot::corners`] of this hotspot, each paired with its
+    /// [`Corner`] label, e.g. for attaching drag handles in an editor.
+    ///
+    /// The corners are yielded counter-clockwise from the lower-left:
+    /// `(min_x, min_y)`, `(max_x, min_y)`, `(max_x, max_y)` then
+    /// `(min_x, max_y)`.
+    #[inline]
+    pub fn labeled_corners(&self) -> impl Iterator<Item = (Corner, Coordinate)> {
+        let Coordinate { x: min_x, y: min_y } = self.lower_left;
+        let Coordinate { x: max_x, y: max_y } = self.upper_right;
+        [
+            (Corner::LowerLeft, Coordinate { x: min_x, y: min_y }),
+            (Corner::LowerRight, Coordinate { x: max_x, y: min_y }),
+            (Corner::UpperRight, Coordinate { x: max_x, y: max_y }),
+            (Corner::UpperLeft, Coordinate { x: min_x, y: max_y }),
+        ]
+        .into_iter()
+    }
+
+    /// Fold over the four [`Hotspot::corners`] of this hotspot, in the same
+    /// order, without materializing them first.
+    #[inline]
+    pub fn fold_corners<B, F: FnMut(B, Coordinate) -> B>(&self, init: B, mut f: F) -> B {
+        let acc = f(init, self.upper_right());
+        let acc = f(acc, self.upper_left());
+        let acc = f(acc, self.lower_left());
+        f(acc, self.lower_right())
+    }
+
+    /// Convert this hotspot to a percentage-based hotspot, relative to the
+    /// provided image dimensions.
+    ///
+    /// Percentages are stored in steps of `1 / CoordinateValue::MAX`, so each
+    /// corner is off by at most half a step, which is `dimension / (2 * MAX)`
+    /// pixels after converting back. Image dimensions can't exceed
+    /// `CoordinateValue::MAX`, so that's at most half a pixel, which
+    /// [`Hotspot::as_pixels`] removes by rounding to the closest pixel.
+    /// Converting back with the same dimensions therefore always gives the
+    /// original hotspot, for corners within the image. Converting back with
+    /// different dimensions can be off by a pixel, and corners outside the
+    /// image are clamped to it.
     #[inline]
     pub const fn as_percentage(
         this: Self,
@@ -156,708 +550,5160 @@ impl Hotspot<PixelRepr> {
             .with_repr::<PercentageRepr>()
             .from_percentage((upper_right, lower_left), image_dimensions)
     }
-}
 
-impl Hotspot<PercentageRepr> {
+    /// Calculate the [`Hotspot::overlap`] between this hotspot and a
+    /// percentage-based hotspot, converting `other` to pixels using the
+    /// provided image dimensions.
     #[inline]
-    pub const fn as_pixels(this: Self, image_dimensions: ImageDimensions) -> Hotspot<PixelRepr> {
-        Hotspot {
-            upper_right: this.upper_right(image_dimensions),
-            lower_left: this.lower_left(image_dimensions),
-            _repr: PhantomData,
-        }
+    pub const fn overlap_cross(
+        &self,
+        other: &Hotspot<PercentageRepr>,
+        image_dimensions: ImageDimensions,
+    ) -> f32 {
+        self.overlap(&Hotspot::as_pixels(*other, image_dimensions))
     }
-}
 
-impl Hotspot<PercentageRepr> {
-    /// Get the upper-right coordinate in pixel values, given the image dimensions.
+    /// Grow this hotspot towards the `target` aspect ratio (width / height),
+    /// keeping it within `container`.
     ///
-    /// This will take the internal percentage and multiply it against the
-    /// height and width of the image to produce exact coordinates.
+    /// Only the shorter side is grown, the hotspot is never shrunk. The grown
+    /// side stays centered on the original hotspot where possible, and is
+    /// shifted to stay within `container` otherwise. If `container` can't
+    /// accommodate the target aspect ratio the grown side is clamped to the
+    /// container, getting as close to the target as possible. The other side
+    /// is left as-is, even if it lies outside of `container`.
     ///
-    /// Note that we will round to the closest pixel automatically.
-    #[inline]
-    pub const fn upper_right(
-        &self,
-        ImageDimensions { height, width }: ImageDimensions,
-    ) -> Coordinate {
-        let Coordinate { x, y } = self.upper_right;
+    /// A non-positive or non-finite `target`, a zero-sized hotspot, or a
+    /// hotspot whose shorter side already spans at least the whole container
+    /// returns the hotspot unchanged.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn grow_to_aspect_within(&self, target: f32, container: &Self) -> Self {
+        let width = self.width();
+        let height = self.height();
+
+        if !target.is_finite() || target <= 0.0 || (width == 0 && height == 0) {
+            return *self;
+        }
 
-        let x: CoordinateValue = div_round_closest(
-            x as InternalCalculationType * width as InternalCalculationType,
-            CoordinateValue::MAX as InternalCalculationType,
-        ) as CoordinateValue;
+        let target = target as f64;
+        let mut result = *self;
+        if (width as f64) < (height as f64) * target {
+            if width >= container.width() {
+                return *self;
+            }
+            let new_width = float::round(height as f64 * target).min(CoordinateValue::MAX as f64)
+                as CoordinateValue;
+            let (lo, hi) = fit_span(
+                self.lower_left.x,
+                self.upper_right.x,
+                max!(new_width, width),
+                container.lower_left.x,
+                container.upper_right.x,
+            );
+            result.lower_left.x = lo;
+            result.upper_right.x = hi;
+        } else {
+            if height >= container.height() {
+                return *self;
+            }
+            let new_height = float::round(width as f64 / target).min(CoordinateValue::MAX as f64)
+                as CoordinateValue;
+            let (lo, hi) = fit_span(
+                self.lower_left.y,
+                self.upper_right.y,
+                max!(new_height, height),
+                container.lower_left.y,
+                container.upper_right.y,
+            );
+            result.lower_left.y = lo;
+            result.upper_right.y = hi;
+        }
 
-        let y: CoordinateValue = div_round_closest(
-            y as InternalCalculationType * height as InternalCalculationType,
-            CoordinateValue::MAX as InternalCalculationType,
-        ) as CoordinateValue;
+        debug_check!(result, "Hotspot::grow_to_aspect_within")
+    }
 
-        Coordinate { x, y }
+    /// Scale this hotspot by independent horizontal (`fx`) and vertical (`fy`)
+    /// factors, e.g. to follow a non-uniform resize of the image.
+    ///
+    /// Each corner is scaled relative to the origin, rounded to the closest
+    /// pixel and clamped to the provided image dimensions (which should be the
+    /// dimensions after resizing).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn scale_xy(&self, fx: f32, fy: f32, image_dimensions: ImageDimensions) -> Self {
+        let ImageDimensions { width, height } = image_dimensions;
+        let (fx, fy) = (fx as f64, fy as f64);
+
+        Hotspot::builder().from_pixels((
+            Coordinate {
+                x: scale_value(self.lower_left.x, fx, width),
+                y: scale_value(self.lower_left.y, fy, height),
+            },
+            Coordinate {
+                x: scale_value(self.upper_right.x, fx, width),
+                y: scale_value(self.upper_right.y, fy, height),
+            },
+        ))
     }
 
-    /// Get the upper-left coordinate in pixel values, given the image dimensions.
+    /// Grow each side of this hotspot by a fraction of its own size, e.g. to
+    /// add context around a crop for a model.
     ///
-    /// This will take the internal percentage and multiply it against the
-    /// height and width of the image to produce exact coordinates.
+    /// The left and right sides each move out by `ratio * width`, and the top
+    /// and bottom by `ratio * height`, rounded to the closest pixel, so a
+    /// `ratio` of `0.1` adds 10% context on every side and the hotspot stays
+    /// centered. The result is clamped to the image, so it grows less where it
+    /// meets an edge. A negative or NaN `ratio` is treated as `0.0`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn expand_by_ratio(&self, ratio: f32, image_dimensions: ImageDimensions) -> Self {
+        let margin_x = scale_value(self.width(), ratio as f64, CoordinateValue::MAX);
+        let margin_y = scale_value(self.height(), ratio as f64, CoordinateValue::MAX);
+
+        Self {
+            upper_right: Coordinate {
+                x: self.upper_right.x.saturating_add(margin_x),
+                y: self.upper_right.y.saturating_add(margin_y),
+            },
+            lower_left: Coordinate {
+                x: self.lower_left.x.saturating_sub(margin_x),
+                y: self.lower_left.y.saturating_sub(margin_y),
+            },
+            _repr: PhantomData,
+        }
+        .clamp_to(image_dimensions)
+    }
+
+    /// Scale this hotspot by `factor` about an `anchor` point, e.g. to follow
+    /// a zoom centered on the cursor.
     ///
-    /// Note that we will round to the closest pixel automatically.
-    #[inline]
-    pub const fn upper_left(
+    /// The offset of each corner from `anchor` is multiplied by `factor`, so
+    /// the anchor itself stays fixed. Scaling about [`Hotspot::center`] grows
+    /// or shrinks the hotspot evenly on every side. The corners are rounded to
+    /// the closest pixel, clamped to the image and re-normalized.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn scale_about(
         &self,
-        ImageDimensions { height, width }: ImageDimensions,
-    ) -> Coordinate {
-        let Coordinate { x, y } = Coordinate {
-            x: self.upper_right.x,
-            y: self.lower_left.y,
+        anchor: Coordinate,
+        factor: f32,
+        image_dimensions: ImageDimensions,
+    ) -> Self {
+        let factor = factor as f64;
+        let scale = |value: CoordinateValue, anchor: CoordinateValue, bound: CoordinateValue| {
+            let anchor = anchor as f64;
+            // Float to int casts saturate, so negative values (and NaN) become 0.
+            let scaled = float::round(anchor + (value as f64 - anchor) * factor) as CoordinateValue;
+            min!(scaled, bound)
         };
 
-        let x: CoordinateValue = div_round_closest(
-            x as InternalCalculationType * width as InternalCalculationType,
-            CoordinateValue::MAX as InternalCalculationType,
-        ) as CoordinateValue;
-
-        let y: CoordinateValue = div_round_closest(
-            y as InternalCalculationType * height as InternalCalculationType,
-            CoordinateValue::MAX as InternalCalculationType,
-        ) as CoordinateValue;
-
-        Coordinate { x, y }
+        Hotspot::builder().from_pixels((
+            Coordinate {
+                x: scale(self.lower_left.x, anchor.x, image_dimensions.width),
+                y: scale(self.lower_left.y, anchor.y, image_dimensions.height),
+            },
+            Coordinate {
+                x: scale(self.upper_right.x, anchor.x, image_dimensions.width),
+                y: scale(self.upper_right.y, anchor.y, image_dimensions.height),
+            },
+        ))
     }
 
-    /// Get the lower-left coordinate in pixel values, given the image dimensions.
-    ///
-    /// This will take the internal percentage and multiply it against the
-    /// height and width of the image to produce exact coordinates.
+    /// Scale this hotspot evenly about its [`Hotspot::center`] so its area is
+    /// close to `target_area`, keeping its aspect ratio, e.g. to normalize the
+    /// sizes of detections.
     ///
-    /// Note that we will round to the closest pixel automatically.
-    #[inline]
-    pub const fn lower_left(
+    /// Both sides are scaled by `sqrt(target_area / area)`, then rounded and
+    /// clamped to the image as in [`Hotspot::scale_about`], so the resulting
+    /// area is only approximately `target_area`. A hotspot with zero area
+    /// can't be scaled to any other area, so it's returned unchanged.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn scale_to_area(
         &self,
-        ImageDimensions { height, width }: ImageDimensions,
-    ) -> Coordinate {
-        let Coordinate { x, y } = self.lower_left;
+        target_area: InternalCalculationType,
+        image_dimensions: ImageDimensions,
+    ) -> Self {
+        let area = self.area();
+        if area == 0 {
+            return *self;
+        }
 
-        let x: CoordinateValue = div_round_closest(
-            x as InternalCalculationType * width as InternalCalculationType,
-            CoordinateValue::MAX as InternalCalculationType,
-        ) as CoordinateValue;
+        let factor = float::sqrt(target_area as f64 / area as f64);
+        self.scale_about(self.center(), factor as f32, image_dimensions)
+    }
 
-        let y: CoordinateValue = div_round_closest(
-            y as InternalCalculationType * height as InternalCalculationType,
-            CoordinateValue::MAX as InternalCalculationType,
-        ) as CoordinateValue;
+    /// Map this hotspot from an image of size `src` into a `dst` canvas which
+    /// the image is letterboxed into, e.g. to follow the resize and padding
+    /// applied when preprocessing an image for a model.
+    ///
+    /// The image is scaled to fit within `dst` while keeping its aspect ratio,
+    /// then centered along the other axis. The mapped corners are rounded to
+    /// the closest pixel and clamped to `dst`. If `src` has a zero dimension
+    /// this returns a zero-area hotspot at the center of `dst`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn letterbox_into(&self, src: ImageDimensions, dst: ImageDimensions) -> Self {
+        let scale = if src.width == 0 || src.height == 0 {
+            0.0
+        } else {
+            f64::min(
+                dst.width as f64 / src.width as f64,
+                dst.height as f64 / src.height as f64,
+            )
+        };
+        let offset_x = (dst.width as f64 - src.width as f64 * scale) / 2.0;
+        let offset_y = (dst.height as f64 - src.height as f64 * scale) / 2.0;
 
-        Coordinate { x, y }
+        let map = |value: CoordinateValue, offset: f64, bound: CoordinateValue| {
+            // Float to int casts saturate, so negative values (and NaN) become 0.
+            let mapped = float::round(value as f64 * scale + offset) as CoordinateValue;
+            min!(mapped, bound)
+        };
+
+        Hotspot::builder().from_pixels((
+            Coordinate {
+                x: map(self.lower_left.x, offset_x, dst.width),
+                y: map(self.lower_left.y, offset_y, dst.height),
+            },
+            Coordinate {
+                x: map(self.upper_right.x, offset_x, dst.width),
+                y: map(self.upper_right.y, offset_y, dst.height),
+            },
+        ))
     }
 
-    /// Get the lower-right coordinate in pixel values, given the image dimensions.
-    ///
-    /// This will take the internal percentage and multiply it against the
-    /// height and width of the image to produce exact coordinates.
+    /// Blend two hotspots together by taking the weighted average of each of
+    /// their corners, e.g. to combine a prediction with a measurement.
     ///
-    /// Note that we will round to the closest pixel automatically.
-    #[inline]
-    pub const fn lower_right(
-        &self,
-        ImageDimensions { height, width }: ImageDimensions,
-    ) -> Coordinate {
-        let Coordinate { x, y } = Coordinate {
-            x: self.lower_left.x,
-            y: self.upper_right.y,
+    /// The weights are normalized so they don't need to sum to 1, and equal
+    /// weights produce the midpoint between the two hotspots. The averaged
+    /// corners are rounded to the closest pixel. If the weights don't sum to a
+    /// positive, finite value this hotspot is returned unchanged.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn blend(&self, other: &Self, self_weight: f32, other_weight: f32) -> Self {
+        let total = self_weight as f64 + other_weight as f64;
+        if !total.is_finite() || total <= 0.0 {
+            return *self;
+        }
+
+        let self_weight = self_weight as f64 / total;
+        let other_weight = other_weight as f64 / total;
+        let average = |a: CoordinateValue, b: CoordinateValue| {
+            float::round(a as f64 * self_weight + b as f64 * other_weight) as CoordinateValue
         };
 
-        let x: CoordinateValue = div_round_closest(
-            x as InternalCalculationType * width as InternalCalculationType,
-            CoordinateValue::MAX as InternalCalculationType,
-        ) as CoordinateValue;
-
-        let y: CoordinateValue = div_round_closest(
-            y as InternalCalculationType * height as InternalCalculationType,
-            CoordinateValue::MAX as InternalCalculationType,
-        ) as CoordinateValue;
-
-        Coordinate { x, y }
+        Hotspot::builder().from_pixels((
+            Coordinate {
+                x: average(self.lower_left.x, other.lower_left.x),
+                y: average(self.lower_left.y, other.lower_left.y),
+            },
+            Coordinate {
+                x: average(self.upper_right.x, other.upper_right.x),
+                y: average(self.upper_right.y, other.upper_right.y),
+            },
+        ))
     }
-}
 
-impl<R> Hotspot<R> {
-    /// Calculate the overlap between two hotspots as a value between 0 and 1
-    /// where 0 is no overlap and 1 is complete overlap.
+    /// Get this hotspot as an axis-aligned bounding box of
+    /// `[min_x, min_y, max_x, max_y]`, normalized by the image dimensions to
+    /// lie between `0.0` and `1.0`.
     ///
-    /// Note that this implementation is not perfect for calculating e.g. if two
-    /// hotspots should be merged becuase if the size of two hotspots are of
-    /// very different sizes then this may not report much overlap at all.
+    /// This is the format expected by many computer vision libraries. If the
+    /// image has a zero dimension the values along that axis aren't finite.
+    #[inline]
+    pub const fn as_aabb_f64(&self, image_dimensions: ImageDimensions) -> [f64; 4] {
+        let width = image_dimensions.width as f64;
+        let height = image_dimensions.height as f64;
+
+        [
+            self.lower_left.x as f64 / width,
+            self.lower_left.y as f64 / height,
+            self.upper_right.x as f64 / width,
+            self.upper_right.y as f64 / height,
+        ]
+    }
+
+    /// Get the four corners of this hotspot as `[x, y]` vertices normalized by
+    /// the image dimensions to lie between `0.0` and `1.0`, e.g. for a polygon
+    /// renderer or vertex buffer.
     ///
-    /// E.g.
-    /// > h1: 0,0 to 20,20 (area 400) \
-    /// > h2: 5,5 to 15,15 (area 100) \
-    /// > intersection: 5,5 to 15,15 (area 100) \
-    /// > union: 400 + 100 - 100 = 400 \
-    /// > overlap: 100 / 400 = 0.25
+    /// The vertices are the [`Hotspot::corners`] starting from the upper-left,
+    /// which is counter-clockwise with the origin in the lower-left:
+    /// `[min_x, max_y]`, `[min_x, min_y]`, `[max_x, min_y]` then
+    /// `[max_x, max_y]`.
+    pub fn to_polygon_f32(&self, image_dimensions: ImageDimensions) -> [[f32; 2]; 4] {
+        let width = image_dimensions.width as f64;
+        let height = image_dimensions.height as f64;
+
+        let [upper_right, upper_left, lower_left, lower_right] = self.corners();
+        [upper_left, lower_left, lower_right, upper_right].map(|corner| {
+            [
+                (corner.x as f64 / width) as f32,
+                (corner.y as f64 / height) as f32,
+            ]
+        })
+    }
+
+    /// Create a hotspot from a normalized `[min_x, min_y, max_x, max_y]`
+    /// bounding box, the reverse of [`Hotspot::as_aabb_f64`].
     ///
-    /// If you need to decide if one hotspot should be merged into another
-    /// consider using the [`Hotspot::overlap_in`] function instead.
-    pub const fn overlap(&self, other: &Self) -> f32 {
-        // https://stackoverflow.com/questions/9324339/how-much-do-two-rectangles-overlap
-        let Coordinate { x: xa2, y: ya2 } = self.upper_right;
-        let Coordinate { x: xa1, y: ya1 } = self.lower_left;
-        let Coordinate { x: xb2, y: yb2 } = other.upper_right;
-        let Coordinate { x: xb1, y: yb1 } = other.lower_left;
+    /// The values are scaled by the image dimensions and rounded to the
+    /// closest pixel, clamped to the image. The corners may be in either order.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn from_aabb_f64(aabb: [f64; 4], image_dimensions: ImageDimensions) -> Self {
+        let [min_x, min_y, max_x, max_y] = aabb;
+        let ImageDimensions { width, height } = image_dimensions;
 
-        // Cast to InternalCalculationType to prevent overflow during area calculation
-        let xa1 = xa1 as InternalCalculationType;
-        let xa2 = xa2 as InternalCalculationType;
-        let ya1 = ya1 as InternalCalculationType;
-        let ya2 = ya2 as InternalCalculationType;
-        let xb1 = xb1 as InternalCalculationType;
-        let xb2 = xb2 as InternalCalculationType;
-        let yb1 = yb1 as InternalCalculationType;
-        let yb2 = yb2 as InternalCalculationType;
-
-        // Calculate area of rectangle A
-        let sa = (xa2 - xa1) * (ya2 - ya1);
-
-        // Calculate area of rectangle B
-        let sb = (xb2 - xb1) * (yb2 - yb1);
-
-        // Calculate intersection dimensions
-        // We use saturating_sub because if the rectangles are disjoint,
-        // min(right) - max(left) would be negative (underflow in unsigned).
-        let intersection_w = min!(xa2, xb2).saturating_sub(max!(xa1, xb1));
-        let intersection_h = min!(ya2, yb2).saturating_sub(max!(ya1, yb1));
+        Hotspot::builder().from_pixels((
+            Coordinate {
+                x: scale_value(width, min_x, width),
+                y: scale_value(height, min_y, height),
+            },
+            Coordinate {
+                x: scale_value(width, max_x, width),
+                y: scale_value(height, max_y, height),
+            },
+        ))
+    }
 
-        // Calculate area of intersection
-        let si = intersection_w * intersection_h;
+    /// Resize this hotspot to exactly `width` by `height` pixels, centered on
+    /// the current center and kept within the image.
+    ///
+    /// Near the edge of the image the resized hotspot is shifted inwards so it
+    /// keeps the requested size, at the cost of no longer sharing the original
+    /// center. If the image is smaller than the requested size along an axis
+    /// the hotspot spans the whole image along that axis instead.
+    #[inline]
+    pub const fn resize_keep_center(
+        &self,
+        width: CoordinateValue,
+        height: CoordinateValue,
+        image_dimensions: ImageDimensions,
+    ) -> Self {
+        let (x1, x2) = fit_span(
+            self.lower_left.x,
+            self.upper_right.x,
+            width,
+            0,
+            image_dimensions.width,
+        );
+        let (y1, y2) = fit_span(
+            self.lower_left.y,
+            self.upper_right.y,
+            height,
+            0,
+            image_dimensions.height,
+        );
 
-        // Calculate area of union
-        // We subtract the intersection from the sum of the two areas.
-        // However, sa + sb can overflow InternalCalculationType if both are large (e.g. u32::MAX).
-        // Since we are calculating a ratio (si / su), we can cast to f32 before summing to avoid overflow
-        // and maintain precision for the division.
-        let su = sa as f32 + sb as f32 - si as f32;
+        debug_check!(
+            Self {
+                upper_right: Coordinate { x: x2, y: y2 },
+                lower_left: Coordinate { x: x1, y: y1 },
+                _repr: PhantomData,
+            },
+            "Hotspot::resize_keep_center"
+        )
+    }
 
-        // Handle zero area union to avoid NaN
-        if su == 0.0 {
-            return 0.0;
+    /// Move each corner of this hotspot towards the matching corner of
+    /// `target` by at most `max_step` pixels along each axis, e.g. to smoothly
+    /// refine a hotspot over several iterations.
+    ///
+    /// Corners within `max_step` of the target snap to it, so repeated calls
+    /// converge on `target`. A `max_step` of zero leaves the hotspot unchanged.
+    #[inline]
+    pub const fn step_toward(&self, target: &Self, max_step: CoordinateValue) -> Self {
+        const fn step(
+            value: CoordinateValue,
+            target: CoordinateValue,
+            max_step: CoordinateValue,
+        ) -> CoordinateValue {
+            if value < target {
+                min!(value.saturating_add(max_step), target)
+            } else {
+                max!(value.saturating_sub(max_step), target)
+            }
         }
 
-        // Calculate overlap %
-        si as f32 / su
+        // Each corner only moves towards a valid target corner, so the corners
+        // can't cross over.
+        debug_check!(
+            Self {
+                upper_right: Coordinate {
+                    x: step(self.upper_right.x, target.upper_right.x, max_step),
+                    y: step(self.upper_right.y, target.upper_right.y, max_step),
+                },
+                lower_left: Coordinate {
+                    x: step(self.lower_left.x, target.lower_left.x, max_step),
+                    y: step(self.lower_left.y, target.lower_left.y, max_step),
+                },
+                _repr: PhantomData,
+            },
+            "Hotspot::step_toward"
+        )
     }
 
-    /// Calculate the % of this Hotspot that is in the other hotspot, returns an
-    /// f32 where 0 is no overlap and 1 is complete overlap.
+    /// Get the largest square which fits inside this hotspot, sharing its
+    /// center, e.g. for placing a square thumbnail inside a detection.
     ///
-    /// Differs from [`Hotspot::overlap`] in that instead of calculating the total area /
-    /// by the overlap it will return the area of self that is overlapped by
-    /// other - regardless of the remaining area of other.
+    /// The side of the square is the shorter of the width and height. When the
+    /// difference between the sides is odd the extra pixel is left on the
+    /// upper side. A zero-area hotspot returns itself.
+    #[inline]
+    pub const fn largest_inscribed_square(&self) -> Self {
+        let width = self.width();
+        let height = self.height();
+        let side = min!(width, height);
+
+        let x = self.lower_left.x + (width - side) / 2;
+        let y = self.lower_left.y + (height - side) / 2;
+
+        debug_check!(
+            Self {
+                upper_right: Coordinate {
+                    x: x + side,
+                    y: y + side,
+                },
+                lower_left: Coordinate { x, y },
+                _repr: PhantomData,
+            },
+            "Hotspot::largest_inscribed_square"
+        )
+    }
+
+    /// Get the inclusive ranges of pixels covered by this hotspot along the x
+    /// and y axes, e.g. for rasterizing the hotspot into a bitmap.
     ///
-    /// E.g.
-    /// > h1: 0,0 to 20,20 (area 400) \
-    /// > h2: 5,5 to 15,15 (area 100) \
-    /// > intersection: 5,5 to 15,15 (area 100) \
-    /// > union: 400 + 100 - 100 = 400 \
-    /// > overlap: 100 / 400 = 1.0
-    pub const fn overlap_in(&self, other: &Self) -> f32 {
-        let Coordinate { x: xa2, y: ya2 } = self.upper_right;
-        let Coordinate { x: xa1, y: ya1 } = self.lower_left;
-        let Coordinate { x: xb2, y: yb2 } = other.upper_right;
-        let Coordinate { x: xb1, y: yb1 } = other.lower_left;
+    /// A zero-area hotspot still covers the pixels along its edge, so a single
+    /// point returns single-element ranges.
+    #[inline]
+    pub const fn pixel_ranges(
+        &self,
+    ) -> (
+        core::ops::RangeInclusive<CoordinateValue>,
+        core::ops::RangeInclusive<CoordinateValue>,
+    ) {
+        (
+            core::ops::RangeInclusive::new(self.lower_left.x, self.upper_right.x),
+            core::ops::RangeInclusive::new(self.lower_left.y, self.upper_right.y),
+        )
+    }
 
-        // Cast to InternalCalculationType to prevent overflow during area calculation
-        let xa1 = xa1 as InternalCalculationType;
-        let xa2 = xa2 as InternalCalculationType;
-        let ya1 = ya1 as InternalCalculationType;
-        let ya2 = ya2 as InternalCalculationType;
-        let xb1 = xb1 as InternalCalculationType;
-        let xb2 = xb2 as InternalCalculationType;
-        let yb1 = yb1 as InternalCalculationType;
-        let yb2 = yb2 as InternalCalculationType;
-
-        // Calculate area of rectangle A (self)
-        let sa = (xa2 - xa1) * (ya2 - ya1);
-
-        // Calculate intersection dimensions
-        // We use saturating_sub because if the rectangles are disjoint,
-        // min(right) - max(left) would be negative (underflow in unsigned).
-        let intersection_w = min!(xa2, xb2).saturating_sub(max!(xa1, xb1));
-        let intersection_h = min!(ya2, yb2).saturating_sub(max!(ya1, yb1));
+    /// Iterate over every pixel covered by this hotspot (see
+    /// [`Hotspot::pixel_ranges`]) in row-major order, starting at the
+    /// lower-left corner.
+    ///
+    /// This yields `(width + 1) * (height + 1)` coordinates, so it is only
+    /// intended for small hotspots.
+    pub fn pixels(&self) -> impl Iterator<Item = Coordinate> {
+        let (xs, ys) = self.pixel_ranges();
+        ys.flat_map(move |y| xs.clone().map(move |x| Coordinate { x, y }))
+    }
 
-        // Calculate area of intersection
-        let si = intersection_w * intersection_h;
+    /// Calculate a cheap Hausdorff-like distance between the outlines of two
+    /// hotspots using only their [`Hotspot::corners`].
+    ///
+    /// For each corner of either hotspot this finds the distance to the
+    /// closest corner of the other hotspot, then returns the largest of those
+    /// distances. Identical hotspots return `0.0`, and a hotspot offset by a
+    /// vector returns the length of that vector.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn corner_hausdorff(&self, other: &Self) -> f32 {
+        let directed = |from: &Self, to: &Self| {
+            let targets = to.corners();
+            from.corners()
+                .iter()
+                .map(|corner| {
+                    targets
+                        .iter()
+                        .map(|target| corner.euclidean_distance(target))
+                        .fold(f32::INFINITY, f32::min)
+                })
+                .fold(0.0, f32::max)
+        };
 
-        // Handle zero area self to avoid NaN
-        if sa == 0 {
+        f32::max(directed(self, other), directed(other, self))
+    }
+
+    /// Calculate the overlap (intersection over union) between the smallest
+    /// circles enclosing each hotspot, as a value between 0 and 1.
+    ///
+    /// Useful as a heuristic when the underlying detections are circular.
+    /// Circles which don't intersect return 0 and identical circles return 1.
+    /// If both hotspots are a single point this returns 0, matching
+    /// [`Hotspot::overlap`].
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn circle_overlap(&self, other: &Self) -> f32 {
+        use core::f64::consts::PI;
+
+        let (ax, ay, ar) = self.enclosing_circle();
+        let (bx, by, br) = other.enclosing_circle();
+        let d = float::sqrt((ax - bx) * (ax - bx) + (ay - by) * (ay - by));
+
+        let intersection = if d >= ar + br {
+            0.0
+        } else if d <= (ar - br).abs() {
+            // One circle is entirely inside the other
+            let r = ar.min(br);
+            PI * r * r
+        } else {
+            // https://mathworld.wolfram.com/Circle-CircleIntersection.html
+            let a_angle = ((d * d + ar * ar - br * br) / (2.0 * d * ar)).clamp(-1.0, 1.0);
+            let b_angle = ((d * d + br * br - ar * ar) / (2.0 * d * br)).clamp(-1.0, 1.0);
+            let kite = (-d + ar + br) * (d + ar - br) * (d - ar + br) * (d + ar + br);
+
+            ar * ar * float::acos(a_angle) + br * br * float::acos(b_angle)
+                - 0.5 * float::sqrt(kite.max(0.0))
+        };
+
+        let union = PI * ar * ar + PI * br * br - intersection;
+        if union <= 0.0 {
             return 0.0;
         }
 
-        // Calculate overlap % relative to self
-        si as f32 / sa as f32
+        (intersection / union).clamp(0.0, 1.0) as f32
     }
 
-    /// Calculates the highest overlap between these two hotspots by taking the maximum value
-    /// of calling [`Hotspot::overlap_in`] for each combination of self and other.
+    /// Get the center and radius of the smallest circle enclosing this
+    /// hotspot, as `(x, y, radius)`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn enclosing_circle(&self) -> (f64, f64, f64) {
+        let (x1, y1) = (self.lower_left.x as f64, self.lower_left.y as f64);
+        let (x2, y2) = (self.upper_right.x as f64, self.upper_right.y as f64);
+        let radius = float::sqrt((x2 - x1) * (x2 - x1) + (y2 - y1) * (y2 - y1)) / 2.0;
+
+        ((x1 + x2) / 2.0, (y1 + y2) / 2.0, radius)
+    }
+
+    /// Returns true if `point` lies within this hotspot, e.g. for hit-testing
+    /// clicks.
+    ///
+    /// The boundary is inclusive, so points on the edges or corners of the
+    /// hotspot are contained.
     #[inline]
-    pub const fn max_overlap(&self, other: &Self) -> f32 {
-        self.overlap_in(other).max(other.overlap_in(self))
+    pub const fn contains_point(&self, point: Coordinate) -> bool {
+        self.lower_left.x <= point.x
+            && point.x <= self.upper_right.x
+            && self.lower_left.y <= point.y
+            && point.y <= self.upper_right.y
     }
 
-    /// Combines two hotspots and returns a new hotspot which will fully encompass the two provided hotspots.
+    /// Get the distance from `point` to the closest edge of this hotspot, e.g.
+    /// for feathering a mask towards the edges.
+    ///
+    /// Returns `None` if `point` is outside the hotspot, see
+    /// [`Hotspot::contains_point`]. Points on an edge have a distance of `0.0`.
     #[inline]
-    pub const fn combine_hotspots(this: Self, other: Self) -> Self {
-        Self {
-            upper_right: Coordinate {
-                x: max!(this.upper_right.x, other.upper_right.x),
-                y: max!(this.upper_right.y, other.upper_right.y),
-            },
-            lower_left: Coordinate {
-                x: min!(this.lower_left.x, other.lower_left.x),
-                y: min!(this.lower_left.y, other.lower_left.y),
-            },
-            _repr: PhantomData,
+    pub const fn edge_distance(&self, point: Coordinate) -> Option<f32> {
+        if !self.contains_point(point) {
+            return None;
         }
+
+        let horizontal = min!(point.x - self.lower_left.x, self.upper_right.x - point.x);
+        let vertical = min!(point.y - self.lower_left.y, self.upper_right.y - point.y);
+        Some(min!(horizontal, vertical) as f32)
     }
-}
 
-/// A builder for creating hotspots.
-#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct HotspotBuilder<R> {
-    _marker: PhantomData<R>,
-}
+    /// Returns true if `point` lies within this hotspot expanded by `margin`
+    /// pixels on every side (boundary inclusive), e.g. for hit-testing clicks
+    /// with some tolerance.
+    ///
+    /// The expanded hotspot saturates at the edges of the coordinate space.
+    #[inline]
+    pub const fn contains_with_margin(&self, point: Coordinate, margin: CoordinateValue) -> bool {
+        self.lower_left.x.saturating_sub(margin) <= point.x
+            && point.x <= self.upper_right.x.saturating_add(margin)
+            && self.lower_left.y.saturating_sub(margin) <= point.y
+            && point.y <= self.upper_right.y.saturating_add(margin)
+    }
 
-impl core::fmt::Debug for HotspotBuilder<PixelRepr> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.debug_struct("HotspotBuilder")
-            .field("kind", &"Pixel Representation")
-            .finish()
+    /// Get the `(column, row)` of the cell containing this hotspot's
+    /// [`Hotspot::center`] within a grid of square cells starting at the
+    /// origin, e.g. as a coarse spatial hash.
+    ///
+    /// A `cell_size` of zero is treated as one.
+    #[inline]
+    #[allow(
+        clippy::unnecessary_cast,
+        reason = "CoordinateValue is already u32 under high_precision"
+    )]
+    pub const fn center_cell(&self, cell_size: CoordinateValue) -> (u32, u32) {
+        let cell_size = max!(cell_size, 1);
+        let center = self.center();
+
+        ((center.x / cell_size) as u32, (center.y / cell_size) as u32)
     }
-}
 
-impl core::fmt::Debug for HotspotBuilder<PercentageRepr> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.debug_struct("HotspotBuilder")
-            .field("kind", &"Percentage Representation")
-            .finish()
+    /// Shrink this hotspot to the tightest box containing every pixel (see
+    /// [`Hotspot::pixels`]) for which `is_content` returns true, e.g. to trim
+    /// empty space around a mask.
+    ///
+    /// Returns `None` if no pixel within the hotspot is content. This visits
+    /// every pixel in the hotspot, so it is only intended for small hotspots.
+    pub fn trim<F: Fn(Coordinate) -> bool>(&self, is_content: F) -> Option<Self> {
+        let mut content = self.pixels().filter(|pixel| is_content(*pixel));
+        let first = content.next()?;
+
+        let (lower_left, upper_right) =
+            content.fold((first, first), |(lower_left, upper_right), pixel| {
+                (
+                    Coordinate {
+                        x: min!(lower_left.x, pixel.x),
+                        y: min!(lower_left.y, pixel.y),
+                    },
+                    Coordinate {
+                        x: max!(upper_right.x, pixel.x),
+                        y: max!(upper_right.y, pixel.y),
+                    },
+                )
+            });
+
+        Some(debug_check!(
+            Self {
+                upper_right,
+                lower_left,
+                _repr: PhantomData,
+            },
+            "Hotspot::trim"
+        ))
     }
-}
 
-impl Hotspot {
-    /// Create a builder for a hotspot.
+    /// Pack the four coordinates of this hotspot into a single `u64`.
+    ///
+    /// The coordinates are stored in four 16-bit lanes, from most to least
+    /// significant: `x1`, `y1`, `x2`, `y2` (matching the serialized field
+    /// names). Use [`Hotspot::from_u64`] to unpack the value again.
+    ///
+    /// Only available when the `high_precision` feature is disabled.
+    #[cfg(not(feature = "high_precision"))]
     #[inline]
-    pub const fn builder() -> HotspotBuilder<PixelRepr> {
-        HotspotBuilder {
-            _marker: core::marker::PhantomData,
-        }
+    pub const fn pack_u64(&self) -> u64 {
+        ((self.upper_right.x as u64) << 48)
+            | ((self.upper_right.y as u64) << 32)
+            | ((self.lower_left.x as u64) << 16)
+            | (self.lower_left.y as u64)
     }
-}
 
-impl<R: InternalRepr> HotspotBuilder<R> {
-    /// Set the internal representation for the hotspot.
+    /// Unpack a hotspot previously packed with [`Hotspot::pack_u64`].
+    ///
+    /// The corners are normalized in the same way as
+    /// [`HotspotBuilder::from_pixels`], so any `u64` produces a valid hotspot.
+    ///
+    /// Only available when the `high_precision` feature is disabled.
+    #[cfg(not(feature = "high_precision"))]
     #[inline]
-    pub const fn with_repr<NewR: InternalRepr>(self) -> HotspotBuilder<NewR> {
-        HotspotBuilder {
-            _marker: core::marker::PhantomData,
+    pub const fn from_u64(v: u64) -> Self {
+        Hotspot::builder().from_pixels((
+            Coordinate {
+                x: (v >> 48) as CoordinateValue,
+                y: (v >> 32) as CoordinateValue,
+            },
+            Coordinate {
+                x: (v >> 16) as CoordinateValue,
+                y: v as CoordinateValue,
+            },
+        ))
+    }
+
+    /// Format this hotspot as a single CSV line of the form
+    /// `ll_x,ll_y,ur_x,ur_y`, suitable for importing into a spreadsheet.
+    ///
+    /// Use [`Hotspot::from_csv_line`] to parse the line again.
+    #[cfg(feature = "alloc")]
+    pub fn to_csv_line(&self) -> alloc::string::String {
+        alloc::format!(
+            "{},{},{},{}",
+            self.lower_left.x,
+            self.lower_left.y,
+            self.upper_right.x,
+            self.upper_right.y
+        )
+    }
+
+    /// Render a batch of hotspots as a standalone SVG document sized to the
+    /// image, e.g. for eyeballing detections without a GUI.
+    ///
+    /// Each hotspot becomes an unfilled `<rect>`, flipped vertically since SVG
+    /// places its origin in the upper-left rather than the lower-left. Stroke
+    /// colors cycle through a small palette so neighbouring hotspots can be
+    /// told apart.
+    #[cfg(feature = "alloc")]
+    pub fn batch_to_svg(
+        boxes: &[Hotspot<PixelRepr>],
+        image_dimensions: ImageDimensions,
+    ) -> alloc::string::String {
+        const PALETTE: [&str; 6] = [
+            "#e6194b", "#3cb44b", "#4363d8", "#f58231", "#911eb4", "#42d4f4",
+        ];
+
+        let ImageDimensions { width, height } = image_dimensions;
+        let mut svg = alloc::format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n"
+        );
+        for (hotspot, color) in boxes.iter().zip(PALETTE.iter().cycle()) {
+            svg.push_str(&alloc::format!(
+                "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"none\" stroke=\"{color}\" />\n",
+                hotspot.lower_left.x,
+                height.saturating_sub(hotspot.upper_right.y),
+                hotspot.width(),
+                hotspot.height(),
+            ));
         }
+        svg.push_str("</svg>\n");
+
+        svg
     }
-}
 
-impl HotspotBuilder<PixelRepr> {
-    /// Create a pixel-based hotspot from top-left and bottom-right coordinates.
+    /// Parse a hotspot from a CSV line produced by [`Hotspot::to_csv_line`].
     ///
-    /// NOTE: we assume that these are provided with the origin in the bottom left, e.g.
+    /// Whitespace around each field is ignored and the corners are normalized
+    /// as in [`HotspotBuilder::from_pixels`].
+    #[cfg(feature = "alloc")]
+    pub fn from_csv_line(line: &str) -> Result<Self, HotspotError> {
+        let found = line.split(',').count();
+        let mut fields = line.split(',').map(|field| {
+            field
+                .trim()
+                .parse::<CoordinateValue>()
+                .map_err(HotspotError::InvalidCoordinate)
+        });
+
+        let (Some(x1), Some(y1), Some(x2), Some(y2), None) = (
+            fields.next(),
+            fields.next(),
+            fields.next(),
+            fields.next(),
+            fields.next(),
+        ) else {
+            return Err(HotspotError::InvalidFieldCount { expected: 4, found });
+        };
+
+        Ok(Hotspot::builder()
+            .from_pixels((Coordinate { x: x1?, y: y1? }, Coordinate { x: x2?, y: y2? })))
+    }
+
+    /// Split this hotspot into `n` horizontal strips of equal height, stacked
+    /// from the lower edge upwards, each spanning the full width.
     ///
-    /// X is expected to be up/down (i.e. vertical), Y is expected to be left/right (i.e. Horizontal).
-    #[inline]
-    pub const fn from_pixels(
-        self,
-        (Coordinate { x: x1, y: y1 }, Coordinate { x: x2, y: y2 }): (Coordinate, Coordinate),
-    ) -> Hotspot<PixelRepr> {
-        let upper_right = Coordinate {
-            x: max!(x1, x2),
-            y: max!(y1, y2),
+    /// The strips tile the original hotspot exactly, with any remainder pixels
+    /// added to the last strip. If `n` exceeds the height the leading strips
+    /// have zero height. `n == 0` returns no strips.
+    #[cfg(feature = "alloc")]
+    pub fn horizontal_strips(&self, n: u32) -> alloc::vec::Vec<Self> {
+        split_span(self.lower_left.y, self.upper_right.y, n)
+            .map(|(lo, hi)| Self {
+                upper_right: Coordinate {
+                    y: hi,
+                    ..self.upper_right
+                },
+                lower_left: Coordinate {
+                    y: lo,
+                    ..self.lower_left
+                },
+                _repr: PhantomData,
+            })
+            .collect()
+    }
+
+    /// Split this hotspot into `n` vertical strips of equal width, ordered
+    /// from the left edge, each spanning the full height.
+    ///
+    /// See [`Hotspot::horizontal_strips`] for how remainders are handled.
+    #[cfg(feature = "alloc")]
+    pub fn vertical_strips(&self, n: u32) -> alloc::vec::Vec<Self> {
+        split_span(self.lower_left.x, self.upper_right.x, n)
+            .map(|(lo, hi)| Self {
+                upper_right: Coordinate {
+                    x: hi,
+                    ..self.upper_right
+                },
+                lower_left: Coordinate {
+                    x: lo,
+                    ..self.lower_left
+                },
+                _repr: PhantomData,
+            })
+            .collect()
+    }
+
+    /// Get the border boxes of `thickness` pixels surrounding this hotspot,
+    /// clipped to the image, e.g. for extracting the context around a
+    /// detection.
+    ///
+    /// The boxes are returned in the order above, below, left then right. The
+    /// boxes above and below also cover the corners of the ring, so none of
+    /// the boxes overlap. Boxes which would have zero area, e.g. because the
+    /// hotspot touches the edge of the image, are omitted.
+    #[cfg(feature = "alloc")]
+    pub fn surrounding_ring(
+        &self,
+        thickness: CoordinateValue,
+        image_dimensions: ImageDimensions,
+    ) -> alloc::vec::Vec<Self> {
+        let ImageDimensions { width, height } = image_dimensions;
+        let inner_ll = Coordinate {
+            x: min!(self.lower_left.x, width),
+            y: min!(self.lower_left.y, height),
+        };
+        let inner_ur = Coordinate {
+            x: min!(self.upper_right.x, width),
+            y: min!(self.upper_right.y, height),
+        };
+        let outer_ll = Coordinate {
+            x: inner_ll.x.saturating_sub(thickness),
+            y: inner_ll.y.saturating_sub(thickness),
+        };
+        let outer_ur = Coordinate {
+            x: min!(inner_ur.x.saturating_add(thickness), width),
+            y: min!(inner_ur.y.saturating_add(thickness), height),
         };
 
-        let lower_left = Coordinate {
-            x: min!(x1, x2),
-            y: min!(y1, y2),
+        let rect = |x1, y1, x2, y2| Self {
+            upper_right: Coordinate { x: x2, y: y2 },
+            lower_left: Coordinate { x: x1, y: y1 },
+            _repr: PhantomData,
         };
+        [
+            rect(outer_ll.x, inner_ur.y, outer_ur.x, outer_ur.y),
+            rect(outer_ll.x, outer_ll.y, outer_ur.x, inner_ll.y),
+            rect(outer_ll.x, inner_ll.y, inner_ll.x, inner_ur.y),
+            rect(inner_ur.x, inner_ll.y, outer_ur.x, inner_ur.y),
+        ]
+        .into_iter()
+        .filter(|border| border.area() > 0)
+        .collect()
+    }
 
-        Hotspot {
-            upper_right,
-            lower_left,
-            _repr: core::marker::PhantomData,
+    /// Subtract `other` from this hotspot, returning the non-overlapping boxes
+    /// which together cover the parts of this hotspot outside of `other`.
+    ///
+    /// Returns no boxes if `other` covers this hotspot entirely, and this
+    /// hotspot unchanged if they don't [`Hotspot::intersection`]. Otherwise the
+    /// boxes are returned in the order above, below, left then right of the
+    /// intersection, with the boxes above and below spanning the full width of
+    /// this hotspot. Boxes which would have zero area are omitted, so a hole in
+    /// the middle of this hotspot produces four boxes.
+    #[cfg(feature = "alloc")]
+    pub fn subtract(&self, other: &Self) -> alloc::vec::Vec<Hotspot<PixelRepr>> {
+        let Some(Self {
+            upper_right: inner_ur,
+            lower_left: inner_ll,
+            ..
+        }) = self.intersection(other)
+        else {
+            return alloc::vec![*self];
+        };
+        let Self {
+            upper_right: outer_ur,
+            lower_left: outer_ll,
+            ..
+        } = *self;
+
+        let rect = |x1, y1, x2, y2| Self {
+            upper_right: Coordinate { x: x2, y: y2 },
+            lower_left: Coordinate { x: x1, y: y1 },
+            _repr: PhantomData,
+        };
+        [
+            rect(outer_ll.x, inner_ur.y, outer_ur.x, outer_ur.y),
+            rect(outer_ll.x, outer_ll.y, outer_ur.x, inner_ll.y),
+            rect(outer_ll.x, inner_ll.y, inner_ll.x, inner_ur.y),
+            rect(inner_ur.x, inner_ll.y, outer_ur.x, inner_ur.y),
+        ]
+        .into_iter()
+        .filter(|remainder| remainder.area() > 0)
+        .collect()
+    }
+
+    /// Partition this hotspot and `other` into their [`Hotspot::intersection`]
+    /// and the parts exclusive to each, as `(intersection, self_only,
+    /// other_only)`.
+    ///
+    /// The exclusive parts are the [`Hotspot::subtract`] of each hotspot from
+    /// the other, so together with the intersection they tile each original
+    /// hotspot exactly. Hotspots which don't intersect give `(None, [self],
+    /// [other])`.
+    #[cfg(feature = "alloc")]
+    pub fn partition_with(
+        &self,
+        other: &Self,
+    ) -> (Option<Self>, alloc::vec::Vec<Self>, alloc::vec::Vec<Self>) {
+        (
+            self.intersection(other),
+            self.subtract(other),
+            other.subtract(self),
+        )
+    }
+
+    /// Recursively split this hotspot into quadrants until every cell has an
+    /// [`Hotspot::area`] of at most `max_area`, returning the leaf cells.
+    ///
+    /// The leaves tile the original hotspot exactly. A hotspot already within
+    /// `max_area` is returned as-is. Cells can't be split below one pixel on
+    /// either axis, so a cell which is one pixel wide is only split along the
+    /// other axis and a 1x1 cell is always a leaf, even if `max_area` is 0.
+    #[cfg(feature = "alloc")]
+    pub fn subdivide_until(&self, max_area: InternalCalculationType) -> alloc::vec::Vec<Self> {
+        let mut leaves = alloc::vec::Vec::new();
+        let mut pending = alloc::vec![*self];
+
+        while let Some(cell) = pending.pop() {
+            let Self {
+                upper_right: Coordinate { x: x2, y: y2 },
+                lower_left: Coordinate { x: x1, y: y1 },
+                _repr: _,
+            } = cell;
+
+            let split_x = x2 - x1 >= 2;
+            let split_y = y2 - y1 >= 2;
+            if cell.area() <= max_area || (!split_x && !split_y) {
+                leaves.push(cell);
+                continue;
+            }
+
+            let mid_x = x1 + (x2 - x1) / 2;
+            let mid_y = y1 + (y2 - y1) / 2;
+            let xs: &[(CoordinateValue, CoordinateValue)] = if split_x {
+                &[(x1, mid_x), (mid_x, x2)]
+            } else {
+                &[(x1, x2)]
+            };
+            let ys: &[(CoordinateValue, CoordinateValue)] = if split_y {
+                &[(y1, mid_y), (mid_y, y2)]
+            } else {
+                &[(y1, y2)]
+            };
+
+            for &(lx, ux) in xs {
+                for &(ly, uy) in ys {
+                    pending.push(Self {
+                        upper_right: Coordinate { x: ux, y: uy },
+                        lower_left: Coordinate { x: lx, y: ly },
+                        _repr: PhantomData,
+                    });
+                }
+            }
         }
+
+        leaves
     }
-}
 
-impl HotspotBuilder<PercentageRepr> {
-    /// Create a percentage-based hotspot from top-left and bottom-right coordinates and image dimensions.
-    #[inline]
-    pub const fn from_percentage(
-        self,
-        input: (Coordinate, Coordinate),
+    /// Returns true if every point in `points` lies within this hotspot, see
+    /// [`Hotspot::contains_point`].
+    ///
+    /// Stops at the first point found outside the hotspot. An empty slice
+    /// returns `true`.
+    #[cfg(feature = "alloc")]
+    pub fn contains_all(&self, points: &[Coordinate]) -> bool {
+        points.iter().all(|point| self.contains_point(*point))
+    }
+
+    /// Returns true if at least one point in `points` lies within this
+    /// hotspot, see [`Hotspot::contains_point`].
+    ///
+    /// Stops at the first point found inside the hotspot. An empty slice
+    /// returns `false`.
+    #[cfg(feature = "alloc")]
+    pub fn contains_any(&self, points: &[Coordinate]) -> bool {
+        points.iter().any(|point| self.contains_point(*point))
+    }
+
+    /// Merge a sequence of hotspots in order, combining the running hotspot
+    /// with each following hotspot using [`Hotspot::combine_hotspots`].
+    ///
+    /// The fold is always performed left-to-right, e.g. for stitching together
+    /// detections across frames. Returns `None` if `boxes` is empty.
+    #[cfg(feature = "alloc")]
+    pub fn merge_chain(boxes: &[Hotspot<PixelRepr>]) -> Option<Hotspot<PixelRepr>> {
+        let (first, rest) = boxes.split_first()?;
+        Some(
+            rest.iter()
+                .fold(*first, |merged, next| Self::combine_hotspots(merged, *next)),
+        )
+    }
+
+    /// Calculate the [`Hotspot::overlap`] between every pair of distinct
+    /// hotspots in `boxes`, as a flat upper-triangular matrix.
+    ///
+    /// Since the overlap is symmetric each pair is only calculated once. The
+    /// pairs `(i, j)` with `i < j` are stored row by row, so for `n` boxes the
+    /// result has `n * (n - 1) / 2` entries and the overlap between boxes `i`
+    /// and `j` is at index `i * n - i * (i + 1) / 2 + (j - i - 1)`.
+    #[cfg(feature = "alloc")]
+    pub fn self_iou_matrix(boxes: &[Hotspot<PixelRepr>]) -> alloc::vec::Vec<f32> {
+        let n = boxes.len();
+        let mut matrix = alloc::vec::Vec::with_capacity(n * n.saturating_sub(1) / 2);
+        for (i, a) in boxes.iter().enumerate() {
+            matrix.extend(boxes[i + 1..].iter().map(|b| a.overlap(b)));
+        }
+
+        matrix
+    }
+
+    /// Greedy non-maximum suppression, returning the indices of the hotspots
+    /// which were kept in descending order of score.
+    ///
+    /// Hotspots are visited from the highest score to the lowest, and each is
+    /// dropped if its [`Hotspot::overlap`] with an already kept hotspot
+    /// exceeds `iou_threshold`. Hotspots with equal scores are visited in
+    /// their original order. See [`Hotspot::dedupe_by_iou`] to prioritise by
+    /// order instead of score.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hotspots` and `scores` have different lengths.
+    #[cfg(feature = "alloc")]
+    pub fn non_max_suppression(
+        hotspots: &[Hotspot<PixelRepr>],
+        scores: &[f32],
+        iou_threshold: f32,
+    ) -> alloc::vec::Vec<usize> {
+        assert_eq!(
+            hotspots.len(),
+            scores.len(),
+            "every hotspot must have exactly one score"
+        );
+
+        let mut order: alloc::vec::Vec<usize> = (0..hotspots.len()).collect();
+        order.sort_by(|&a, &b| scores[b].total_cmp(&scores[a]));
+
+        let mut kept: alloc::vec::Vec<usize> = alloc::vec::Vec::new();
+        for i in order {
+            let suppressed = kept.iter().any(|&k| {
+                hotspots[k].overlaps(&hotspots[i])
+                    && hotspots[k].overlap(&hotspots[i]) > iou_threshold
+            });
+            if !suppressed {
+                kept.push(i);
+            }
+        }
+        kept
+    }
+
+    /// Combine groups of hotspots linked by a [`Hotspot::max_overlap`] above
+    /// `threshold` into single boxes with [`Hotspot::combine_hotspots`].
+    ///
+    /// Hotspots are linked by comparing the original hotspots pairwise, never
+    /// the combined boxes, and links chain transitively, so hotspots which
+    /// don't overlap each other directly still end up in the same box when
+    /// linked by others. Each group of linked hotspots is combined into one
+    /// box, and the boxes are returned sorted, so the result doesn't depend on
+    /// the order of `hotspots`.
+    #[cfg(feature = "alloc")]
+    pub fn merge_overlapping(
+        hotspots: &[Hotspot<PixelRepr>],
+        threshold: f32,
+    ) -> alloc::vec::Vec<Hotspot<PixelRepr>> {
+        /// Find the representative of the group containing `i`, halving the
+        /// path to it along the way.
+        fn find(parent: &mut [usize], mut i: usize) -> usize {
+            while parent[i] != i {
+                parent[i] = parent[parent[i]];
+                i = parent[i];
+            }
+            i
+        }
+
+        let mut parent: alloc::vec::Vec<usize> = (0..hotspots.len()).collect();
+        for i in 0..hotspots.len() {
+            for j in i + 1..hotspots.len() {
+                if hotspots[i].overlaps(&hotspots[j])
+                    && hotspots[i].max_overlap(&hotspots[j]) > threshold
+                {
+                    let (root_i, root_j) = (find(&mut parent, i), find(&mut parent, j));
+                    parent[root_j] = root_i;
+                }
+            }
+        }
+
+        let mut groups: alloc::vec::Vec<Option<Self>> = alloc::vec![None; hotspots.len()];
+        for (i, hotspot) in hotspots.iter().enumerate() {
+            let group = &mut groups[find(&mut parent, i)];
+            *group = Some(match *group {
+                Some(merged) => Self::combine_hotspots(merged, *hotspot),
+                None => *hotspot,
+            });
+        }
+
+        let mut merged: alloc::vec::Vec<Self> = groups.into_iter().flatten().collect();
+        merged.sort_unstable();
+        merged
+    }
+
+    /// Count how many of `boxes` overlap each cell of a grid of square cells
+    /// covering the image, e.g. for a heatmap of annotation density.
+    ///
+    /// The grid starts at the origin and has `ceil(width / cell_size)` columns
+    /// and `ceil(height / cell_size)` rows, with the last column and row
+    /// clipped to the image. Counts are stored row by row starting from the
+    /// bottom row, so the cell at `(column, row)` is at index
+    /// `row * columns + column`. A box only counts towards cells it covers
+    /// with a non-zero area, so zero-area boxes and boxes which just touch a
+    /// cell's edge aren't counted, and parts of boxes outside the image are
+    /// ignored. A `cell_size` of zero is treated as one.
+    #[cfg(feature = "alloc")]
+    pub fn coverage_grid(
+        boxes: &[Hotspot<PixelRepr>],
+        image_dimensions: ImageDimensions,
+        cell_size: CoordinateValue,
+    ) -> alloc::vec::Vec<u32> {
+        let cell_size = max!(cell_size, 1) as usize;
+        let ImageDimensions { width, height } = image_dimensions;
+        let columns = (width as usize).div_ceil(cell_size);
+        let rows = (height as usize).div_ceil(cell_size);
+
+        let mut grid = alloc::vec![0; columns * rows];
+        let image = Self::full(image_dimensions);
+        for hotspot in boxes
+            .iter()
+            .filter_map(|hotspot| hotspot.intersection(&image))
+        {
+            // The intersection is non-empty, so the upper edges are past the
+            // lower edges and the last covered pixel is one before them.
+            let first_column = hotspot.lower_left.x as usize / cell_size;
+            let last_column = (hotspot.upper_right.x as usize - 1) / cell_size;
+            let first_row = hotspot.lower_left.y as usize / cell_size;
+            let last_row = (hotspot.upper_right.y as usize - 1) / cell_size;
+
+            for row in first_row..=last_row {
+                for count in &mut grid[row * columns + first_column..=row * columns + last_column] {
+                    *count += 1;
+                }
+            }
+        }
+
+        grid
+    }
+
+    /// Count the `(true_positives, false_positives, false_negatives)` when
+    /// matching predictions against ground truth at each of the IoU
+    /// `thresholds`, e.g. for precision/recall curves.
+    ///
+    /// Pairs are matched greedily in order of descending [`Hotspot::overlap`],
+    /// each prediction and ground truth matching at most once, and a pair only
+    /// matches if its overlap is at least the threshold. The overlaps are
+    /// calculated once and reused for every threshold.
+    #[cfg(feature = "alloc")]
+    pub fn match_counts(
+        preds: &[Hotspot<PixelRepr>],
+        gts: &[Hotspot<PixelRepr>],
+        thresholds: &[f32],
+    ) -> alloc::vec::Vec<(usize, usize, usize)> {
+        let mut pairs = alloc::vec::Vec::new();
+        for (p, pred) in preds.iter().enumerate() {
+            for (g, gt) in gts.iter().enumerate() {
+                if pred.overlaps(gt) {
+                    pairs.push((pred.overlap(gt), p, g));
+                }
+            }
+        }
+        pairs.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+        let mut pred_matched = alloc::vec![false; preds.len()];
+        let mut gt_matched = alloc::vec![false; gts.len()];
+        thresholds
+            .iter()
+            .map(|&threshold| {
+                pred_matched.fill(false);
+                gt_matched.fill(false);
+
+                let mut true_positives = 0;
+                for &(_, p, g) in pairs.iter().take_while(|(iou, _, _)| *iou >= threshold) {
+                    if !pred_matched[p] && !gt_matched[g] {
+                        pred_matched[p] = true;
+                        gt_matched[g] = true;
+                        true_positives += 1;
+                    }
+                }
+
+                (
+                    true_positives,
+                    preds.len() - true_positives,
+                    gts.len() - true_positives,
+                )
+            })
+            .collect()
+    }
+
+    /// Calculate the overlap (intersection over union) between this hotspot
+    /// and the region covered by all of `others`, e.g. to compare a prediction
+    /// against several ground truth boxes.
+    ///
+    /// Regions where `others` overlap each other are only counted once. An
+    /// empty `others`, or hotspots which all have zero area, returns `0.0`.
+    #[cfg(feature = "alloc")]
+    pub fn overlap_with_union(&self, others: &[Hotspot<PixelRepr>]) -> f32 {
+        let clipped: alloc::vec::Vec<Self> = others
+            .iter()
+            .filter_map(|other| other.clip_to_hotspot(self))
+            .collect();
+        let intersection = union_area(&clipped);
+        if intersection == 0 {
+            return 0.0;
+        }
+
+        let mut all = alloc::vec::Vec::with_capacity(others.len() + 1);
+        all.push(*self);
+        all.extend_from_slice(others);
+        intersection as f32 / union_area(&all) as f32
+    }
+
+    /// Returns a mask with one entry per hotspot in `others`, set when its
+    /// [`Hotspot::overlap`] with this hotspot exceeds `threshold`.
+    ///
+    /// Hotspots which don't [`Hotspot::overlaps`] this one are skipped without
+    /// calculating the overlap, so they're never set in the mask.
+    #[cfg(feature = "alloc")]
+    pub fn overlap_mask(
+        &self,
+        others: &[Hotspot<PixelRepr>],
+        threshold: f32,
+    ) -> alloc::vec::Vec<bool> {
+        others
+            .iter()
+            .map(|other| self.overlaps(other) && self.overlap(other) > threshold)
+            .collect()
+    }
+
+    /// Remove near-duplicate hotspots, returning the indices of the hotspots
+    /// which were kept in their original order.
+    ///
+    /// Hotspots are visited in order, and each is dropped if its
+    /// [`Hotspot::overlap`] with an already kept hotspot exceeds `threshold`.
+    /// Unlike non-maximum suppression there are no scores, so the first
+    /// hotspot of any group of duplicates is always the one kept.
+    #[cfg(feature = "alloc")]
+    pub fn dedupe_by_iou(boxes: &[Hotspot<PixelRepr>], threshold: f32) -> alloc::vec::Vec<usize> {
+        let mut kept: alloc::vec::Vec<usize> = alloc::vec::Vec::new();
+        for (i, candidate) in boxes.iter().enumerate() {
+            let duplicate = kept
+                .iter()
+                .any(|&k| boxes[k].overlaps(candidate) && boxes[k].overlap(candidate) > threshold);
+            if !duplicate {
+                kept.push(i);
+            }
+        }
+        kept
+    }
+}
+
+impl Hotspot<PercentageRepr> {
+    #[inline]
+    pub const fn as_pixels(this: Self, image_dimensions: ImageDimensions) -> Hotspot<PixelRepr> {
+        Hotspot {
+            upper_right: this.upper_right(image_dimensions),
+            lower_left: this.lower_left(image_dimensions),
+            _repr: PhantomData,
+        }
+    }
+}
+
+impl Hotspot<PercentageRepr> {
+    /// Get the upper-right coordinate in pixel values, given the image dimensions.
+    ///
+    /// This will take the internal percentage and multiply it against the
+    /// height and width of the image to produce exact coordinates.
+    ///
+    /// Note that we will round to the closest pixel automatically.
+    #[inline]
+    pub const fn upper_right(
+        &self,
         ImageDimensions { height, width }: ImageDimensions,
-    ) -> Hotspot<PercentageRepr> {
-        // Use the HotspotBuilder<PixelRepr>::from_pixel representation to handle
-        // which point is which.
+    ) -> Coordinate {
+        let Coordinate { x, y } = self.upper_right;
+
+        let x: CoordinateValue = div_round_closest(
+            x as InternalCalculationType * width as InternalCalculationType,
+            CoordinateValue::MAX as InternalCalculationType,
+        ) as CoordinateValue;
+
+        let y: CoordinateValue = div_round_closest(
+            y as InternalCalculationType * height as InternalCalculationType,
+            CoordinateValue::MAX as InternalCalculationType,
+        ) as CoordinateValue;
+
+        Coordinate { x, y }
+    }
+
+    /// Get the upper-left coordinate, `(min_x, max_y)`, in pixel values, given
+    /// the image dimensions.
+    ///
+    /// This will take the internal percentage and multiply it against the
+    /// height and width of the image to produce exact coordinates.
+    ///
+    /// Note that we will round to the closest pixel automatically.
+    #[inline]
+    pub const fn upper_left(
+        &self,
+        ImageDimensions { height, width }: ImageDimensions,
+    ) -> Coordinate {
+        let Coordinate { x, y } = Coordinate {
+            x: self.lower_left.x,
+            y: self.upper_right.y,
+        };
+
+        let x: CoordinateValue = div_round_closest(
+            x as InternalCalculationType * width as InternalCalculationType,
+            CoordinateValue::MAX as InternalCalculationType,
+        ) as CoordinateValue;
+
+        let y: CoordinateValue = div_round_closest(
+            y as InternalCalculationType * height as InternalCalculationType,
+            CoordinateValue::MAX as InternalCalculationType,
+        ) as CoordinateValue;
+
+        Coordinate { x, y }
+    }
+
+    /// Get the lower-left coordinate in pixel values, given the image dimensions.
+    ///
+    /// This will take the internal percentage and multiply it against the
+    /// height and width of the image to produce exact coordinates.
+    ///
+    /// Note that we will round to the closest pixel automatically.
+    #[inline]
+    pub const fn lower_left(
+        &self,
+        ImageDimensions { height, width }: ImageDimensions,
+    ) -> Coordinate {
+        let Coordinate { x, y } = self.lower_left;
+
+        let x: CoordinateValue = div_round_closest(
+            x as InternalCalculationType * width as InternalCalculationType,
+            CoordinateValue::MAX as InternalCalculationType,
+        ) as CoordinateValue;
+
+        let y: CoordinateValue = div_round_closest(
+            y as InternalCalculationType * height as InternalCalculationType,
+            CoordinateValue::MAX as InternalCalculationType,
+        ) as CoordinateValue;
+
+        Coordinate { x, y }
+    }
+
+    /// Get the lower-right coordinate, `(max_x, min_y)`, in pixel values, given
+    /// the image dimensions.
+    ///
+    /// This will take the internal percentage and multiply it against the
+    /// height and width of the image to produce exact coordinates.
+    ///
+    /// Note that we will round to the closest pixel automatically.
+    #[inline]
+    pub const fn lower_right(
+        &self,
+        ImageDimensions { height, width }: ImageDimensions,
+    ) -> Coordinate {
+        let Coordinate { x, y } = Coordinate {
+            x: self.upper_right.x,
+            y: self.lower_left.y,
+        };
+
+        let x: CoordinateValue = div_round_closest(
+            x as InternalCalculationType * width as InternalCalculationType,
+            CoordinateValue::MAX as InternalCalculationType,
+        ) as CoordinateValue;
+
+        let y: CoordinateValue = div_round_closest(
+            y as InternalCalculationType * height as InternalCalculationType,
+            CoordinateValue::MAX as InternalCalculationType,
+        ) as CoordinateValue;
+
+        Coordinate { x, y }
+    }
+
+    /// Returns true if the pixel `point` lies within this hotspot, when
+    /// converted to pixels for an image of `image_dimensions`.
+    ///
+    /// The boundary is inclusive, as with the pixel
+    /// [`Hotspot::contains_point`].
+    #[inline]
+    pub const fn contains_point(
+        &self,
+        point: Coordinate,
+        image_dimensions: ImageDimensions,
+    ) -> bool {
+        Hotspot::as_pixels(*self, image_dimensions).contains_point(point)
+    }
+
+    /// Move this hotspot by `dx` and `dy`, given as fractions of the image
+    /// size between `-1.0` and `1.0`, without converting to pixels.
+    ///
+    /// Each stored corner is shifted by the fraction of
+    /// [`CoordinateValue::MAX`], rounded to the closest value, and clamped to
+    /// the image so the hotspot shrinks against the edges as with
+    /// [`Hotspot::translate`]. A NaN shift leaves that axis unchanged.
+    #[inline]
+    pub const fn translate_ratio(&self, dx: f32, dy: f32) -> Self {
+        const fn shift(value: CoordinateValue, delta: f32) -> CoordinateValue {
+            if delta.is_nan() {
+                return value;
+            }
+            // Float to int casts saturate, so this clamps to `0..=MAX`.
+            (value as f64 + delta as f64 * CoordinateValue::MAX as f64 + 0.5) as CoordinateValue
+        }
+
+        debug_check!(
+            Self {
+                upper_right: Coordinate {
+                    x: shift(self.upper_right.x, dx),
+                    y: shift(self.upper_right.y, dy),
+                },
+                lower_left: Coordinate {
+                    x: shift(self.lower_left.x, dx),
+                    y: shift(self.lower_left.y, dy),
+                },
+                _repr: PhantomData,
+            },
+            "Hotspot::translate_ratio"
+        )
+    }
+
+    /// Get the corners of this hotspot as fractions of the image size between
+    /// `0.0` and `1.0`, ordered `[lower_left.x, lower_left.y, upper_right.x,
+    /// upper_right.y]`.
+    ///
+    /// No image dimensions are needed since these are the stored values
+    /// divided by [`CoordinateValue::MAX`].
+    #[inline]
+    pub const fn as_ratios(&self) -> [f32; 4] {
+        const MAX: f64 = CoordinateValue::MAX as f64;
+
+        [
+            (self.lower_left.x as f64 / MAX) as f32,
+            (self.lower_left.y as f64 / MAX) as f32,
+            (self.upper_right.x as f64 / MAX) as f32,
+            (self.upper_right.y as f64 / MAX) as f32,
+        ]
+    }
+
+    /// Get the corners of this hotspot as exact fractions of the image size,
+    /// each a `(numerator, denominator)` pair, ordered `[lower_left.x,
+    /// lower_left.y, upper_right.x, upper_right.y]`.
+    ///
+    /// Each fraction is the stored value over [`CoordinateValue::MAX`],
+    /// reduced to lowest terms, so e.g. `0` is `(0, 1)` and a full-size edge
+    /// is `(1, 1)`. Unlike [`Hotspot::as_ratios`] this is lossless.
+    #[inline]
+    pub const fn corner_ratios(&self) -> [(CoordinateValue, CoordinateValue); 4] {
+        const fn reduce(value: CoordinateValue) -> (CoordinateValue, CoordinateValue) {
+            let (mut a, mut b) = (value, CoordinateValue::MAX);
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            (value / a, CoordinateValue::MAX / a)
+        }
+
+        [
+            reduce(self.lower_left.x),
+            reduce(self.lower_left.y),
+            reduce(self.upper_right.x),
+            reduce(self.upper_right.y),
+        ]
+    }
+}
+
+impl<R> Hotspot<R> {
+    /// Create a hotspot of any representation from two corners, normalizing
+    /// which corner is which in the same way as [`HotspotBuilder::from_pixels`].
+    ///
+    /// The values are stored as-is without any conversion, so for a
+    /// [`PercentageRepr`] hotspot they must already be percentages of
+    /// [`CoordinateValue::MAX`]. Use [`HotspotBuilder::from_percentage`] to
+    /// convert from pixels instead.
+    #[inline]
+    pub const fn from_corners(c1: Coordinate, c2: Coordinate) -> Self {
         let Hotspot {
             upper_right,
             lower_left,
             _repr: _,
-        } = Hotspot::<PixelRepr>::builder().from_pixels(input);
+        } = Hotspot::builder().from_pixels((c1, c2));
+
+        Self {
+            upper_right,
+            lower_left,
+            _repr: PhantomData,
+        }
+    }
+
+    /// Restore the corner invariant by swapping any inverted coordinates, so
+    /// the lower-left corner is never above or right of the upper-right one.
+    ///
+    /// This is the repair step after any unchecked construction. A hotspot
+    /// which is already valid is returned unchanged.
+    #[inline]
+    pub const fn normalize(&self) -> Self {
+        Self::from_corners(self.lower_left, self.upper_right)
+    }
+
+    /// Decompose this hotspot into its `(lower_left, upper_right)` corners in
+    /// the stored representation.
+    ///
+    /// This is the inverse of [`Hotspot::from_corners`], and for pixel
+    /// hotspots of [`HotspotBuilder::from_pixels`].
+    #[inline]
+    pub const fn into_corners(self) -> (Coordinate, Coordinate) {
+        (self.lower_left, self.upper_right)
+    }
+
+    /// Grow this hotspot outwards by `margin` on every side.
+    ///
+    /// The corners saturate at `0` and [`CoordinateValue::MAX`] rather than
+    /// wrapping. For percentage hotspots `margin` is in the stored units, i.e.
+    /// a fraction of [`CoordinateValue::MAX`].
+    #[inline]
+    pub const fn inflate(&self, margin: CoordinateValue) -> Self {
+        debug_check!(
+            Self {
+                upper_right: Coordinate {
+                    x: self.upper_right.x.saturating_add(margin),
+                    y: self.upper_right.y.saturating_add(margin),
+                },
+                lower_left: Coordinate {
+                    x: self.lower_left.x.saturating_sub(margin),
+                    y: self.lower_left.y.saturating_sub(margin),
+                },
+                _repr: PhantomData,
+            },
+            "Hotspot::inflate"
+        )
+    }
+
+    /// Move this hotspot by `dx` along the x axis and `dy` along the y axis.
+    ///
+    /// Each corner saturates at `0` and [`CoordinateValue::MAX`] rather than
+    /// wrapping, so translating a hotspot into the edge of the coordinate
+    /// space shrinks it against that edge, down to zero size if it's moved
+    /// entirely past the edge. The corners never invert.
+    #[inline]
+    pub const fn translate(&self, dx: i32, dy: i32) -> Self {
+        const fn shift(value: CoordinateValue, delta: i32) -> CoordinateValue {
+            let shifted = value as i64 + delta as i64;
+            max!(min!(shifted, CoordinateValue::MAX as i64), 0) as CoordinateValue
+        }
+
+        debug_check!(
+            Self {
+                upper_right: Coordinate {
+                    x: shift(self.upper_right.x, dx),
+                    y: shift(self.upper_right.y, dy),
+                },
+                lower_left: Coordinate {
+                    x: shift(self.lower_left.x, dx),
+                    y: shift(self.lower_left.y, dy),
+                },
+                _repr: PhantomData,
+            },
+            "Hotspot::translate"
+        )
+    }
+
+    /// Shrink this hotspot inwards by `margin` on every side.
+    ///
+    /// If `margin` is more than half of the width or height, that axis
+    /// collapses to zero size at the [`Hotspot::center`] rather than
+    /// inverting. For percentage hotspots `margin` is in the stored units, as
+    /// with [`Hotspot::inflate`].
+    #[inline]
+    pub const fn deflate(&self, margin: CoordinateValue) -> Self {
+        const fn shrink_span(
+            lower: CoordinateValue,
+            upper: CoordinateValue,
+            margin: CoordinateValue,
+            center: CoordinateValue,
+        ) -> (CoordinateValue, CoordinateValue) {
+            // Compare against the half-size without doubling the margin,
+            // which could overflow.
+            if margin > (upper - lower) / 2 {
+                (center, center)
+            } else {
+                (lower + margin, upper - margin)
+            }
+        }
+
+        let center = self.center();
+        let (x1, x2) = shrink_span(self.lower_left.x, self.upper_right.x, margin, center.x);
+        let (y1, y2) = shrink_span(self.lower_left.y, self.upper_right.y, margin, center.y);
+
+        debug_check!(
+            Self {
+                upper_right: Coordinate { x: x2, y: y2 },
+                lower_left: Coordinate { x: x1, y: y1 },
+                _repr: PhantomData,
+            },
+            "Hotspot::deflate"
+        )
+    }
+
+    /// Get the four corners of this hotspot in its stored representation,
+    /// without converting to pixels.
+    ///
+    /// The corners are ordered counter-clockwise from the upper-right:
+    /// `(max_x, max_y)`, `(min_x, max_y)`, `(min_x, min_y)` then
+    /// `(max_x, min_y)`, matching [`Hotspot::upper_right`],
+    /// [`Hotspot::upper_left`], [`Hotspot::lower_left`] and
+    /// [`Hotspot::lower_right`] for pixel hotspots. For percentage hotspots
+    /// each value is a fraction of [`CoordinateValue::MAX`].
+    #[inline]
+    pub const fn raw_corners(&self) -> [Coordinate; 4] {
+        [
+            self.upper_right,
+            Coordinate {
+                x: self.lower_left.x,
+                y: self.upper_right.y,
+            },
+            self.lower_left,
+            Coordinate {
+                x: self.upper_right.x,
+                y: self.lower_left.y,
+            },
+        ]
+    }
+
+    /// Get the width of this hotspot, along the x axis.
+    #[inline]
+    pub const fn width(&self) -> CoordinateValue {
+        self.upper_right.x - self.lower_left.x
+    }
+
+    /// Get the height of this hotspot, along the y axis.
+    #[inline]
+    pub const fn height(&self) -> CoordinateValue {
+        self.upper_right.y - self.lower_left.y
+    }
+
+    /// Calculate the area of this hotspot.
+    ///
+    /// The area is returned as an [`InternalCalculationType`] so that it can't
+    /// overflow, even for a hotspot spanning the full coordinate range.
+    #[inline]
+    pub const fn area(&self) -> InternalCalculationType {
+        self.width() as InternalCalculationType * self.height() as InternalCalculationType
+    }
+
+    /// Get the center of this hotspot, rounded to the closest coordinate.
+    ///
+    /// This is calculated from the stored corners, so for percentage hotspots
+    /// the center is itself a percentage and doesn't depend on the image
+    /// dimensions. Convert it to pixels for a specific image as needed.
+    #[inline]
+    pub const fn center(&self) -> Coordinate {
+        Coordinate {
+            x: div_round_closest(
+                self.lower_left.x as InternalCalculationType
+                    + self.upper_right.x as InternalCalculationType,
+                2,
+            ) as CoordinateValue,
+            y: div_round_closest(
+                self.lower_left.y as InternalCalculationType
+                    + self.upper_right.y as InternalCalculationType,
+                2,
+            ) as CoordinateValue,
+        }
+    }
+
+    /// Calculate the straight-line distance between the [`Hotspot::center`]s
+    /// of two hotspots.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub fn centroid_distance(&self, other: &Self) -> f32 {
+        self.center().euclidean_distance(&other.center())
+    }
+
+    /// Calculate the ratio between the [`Hotspot::area`] of this hotspot and
+    /// `other`.
+    ///
+    /// If `other` has zero area this returns [`f32::INFINITY`], unless both
+    /// hotspots have zero area in which case they're considered equal and this
+    /// returns `1.0`.
+    #[inline]
+    pub const fn area_ratio(&self, other: &Self) -> f32 {
+        let self_area = self.area();
+        let other_area = other.area();
+
+        if other_area == 0 {
+            return if self_area == 0 { 1.0 } else { f32::INFINITY };
+        }
+
+        self_area as f32 / other_area as f32
+    }
+
+    /// Compare two hotspots by their [`Hotspot::area`], e.g. for use with
+    /// `slice.sort_by(|a, b| a.cmp_by_area(b))`.
+    #[inline]
+    pub fn cmp_by_area(&self, other: &Self) -> core::cmp::Ordering {
+        self.area().cmp(&other.area())
+    }
+
+    /// Returns this hotspot if its [`Hotspot::area`] is at least `min_area`,
+    /// otherwise `None`.
+    ///
+    /// Useful with [`Iterator::filter_map`] to discard small hotspots.
+    #[inline]
+    pub const fn filter_min_area(self, min_area: InternalCalculationType) -> Option<Self> {
+        if self.area() >= min_area {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    /// Calculate the overlap between two hotspots as a value between 0 and 1
+    /// where 0 is no overlap and 1 is complete overlap.
+    ///
+    /// Note that this implementation is not perfect for calculating e.g. if two
+    /// hotspots should be merged becuase if the size of two hotspots are of
+    /// very different sizes then this may not report much overlap at all.
+    ///
+    /// E.g.
+    /// > h1: 0,0 to 20,20 (area 400) \
+    /// > h2: 5,5 to 15,15 (area 100) \
+    /// > intersection: 5,5 to 15,15 (area 100) \
+    /// > union: 400 + 100 - 100 = 400 \
+    /// > overlap: 100 / 400 = 0.25
+    ///
+    /// If you need to decide if one hotspot should be merged into another
+    /// consider using the [`Hotspot::overlap_in`] function instead.
+    #[inline(always)]
+    pub const fn overlap(&self, other: &Self) -> f32 {
+        // https://stackoverflow.com/questions/9324339/how-much-do-two-rectangles-overlap
+        let Coordinate { x: xa2, y: ya2 } = self.upper_right;
+        let Coordinate { x: xa1, y: ya1 } = self.lower_left;
+        let Coordinate { x: xb2, y: yb2 } = other.upper_right;
+        let Coordinate { x: xb1, y: yb1 } = other.lower_left;
+
+        // Calculate intersection dimensions first so disjoint hotspots, the
+        // common case when comparing many hotspots, are rejected before any
+        // area calculations.
+        // We use saturating_sub because if the rectangles are disjoint,
+        // min(right) - max(left) would be negative (underflow in unsigned).
+        let intersection_w = min!(xa2, xb2).saturating_sub(max!(xa1, xb1));
+        let intersection_h = min!(ya2, yb2).saturating_sub(max!(ya1, yb1));
+        if intersection_w == 0 || intersection_h == 0 {
+            return 0.0;
+        }
+
+        // Cast to InternalCalculationType to prevent overflow during area calculation
+        // Calculate area of intersection
+        let si =
+            intersection_w as InternalCalculationType * intersection_h as InternalCalculationType;
+
+        // Calculate area of rectangles A and B
+        let sa = (xa2 - xa1) as InternalCalculationType * (ya2 - ya1) as InternalCalculationType;
+        let sb = (xb2 - xb1) as InternalCalculationType * (yb2 - yb1) as InternalCalculationType;
+
+        // Calculate area of union
+        // We subtract the intersection from the sum of the two areas.
+        // However, sa + sb can overflow InternalCalculationType if both are large (e.g. u32::MAX).
+        // Since we are calculating a ratio (si / su), we can cast to f64 before summing to avoid overflow.
+        // f32 doesn't have enough precision for the areas under `high_precision`, so only the final
+        // ratio is cast down.
+        let su = sa as f64 + sb as f64 - si as f64;
+
+        // Calculate overlap %
+        unit_ratio(si as f64, su)
+    }
+
+    /// Calculate the [`Hotspot::overlap`] (intersection over union) between
+    /// two hotspots as an exact, reduced ratio, avoiding any floating point
+    /// rounding.
+    ///
+    /// The union can't exceed the area of the whole coordinate space, so it
+    /// always fits in an [`InternalCalculationType`]. If the union has zero
+    /// area this returns `0 / 1`.
+    #[cfg(feature = "rational")]
+    pub fn iou_ratio(&self, other: &Self) -> num_rational::Ratio<InternalCalculationType> {
+        let intersection = self.intersection_area(other);
+        // The intersection is part of both areas, so subtract it before adding
+        // the other area to avoid overflowing.
+        let union = self.area() - intersection + other.area();
+        if union == 0 {
+            return num_rational::Ratio::new(0, 1);
+        }
+
+        num_rational::Ratio::new(intersection, union)
+    }
+
+    /// Calculate the [`Hotspot::overlap`] between two hotspots, returning
+    /// `None` if both have zero area.
+    ///
+    /// The overlap of two zero-area hotspots is undefined (zero divided by
+    /// zero), which [`Hotspot::overlap`] reports as `0.0`. This makes that
+    /// case distinguishable from hotspots which genuinely don't overlap. If
+    /// only one hotspot has zero area the overlap is `Some(0.0)`.
+    #[inline]
+    pub const fn checked_overlap(&self, other: &Self) -> Option<f32> {
+        if self.area() == 0 && other.area() == 0 {
+            return None;
+        }
+
+        Some(self.overlap(other))
+    }
+
+    /// Calculate [`Hotspot::overlap`] using precomputed [`Hotspot::area`]s for
+    /// both hotspots.
+    ///
+    /// Useful when comparing the same hotspots many times, so the areas only
+    /// need to be calculated once, see [`cached::CachedHotspot`].
+    /// `self_area` and `other_area` must be the areas of `self` and `other`.
+    #[inline]
+    pub const fn overlap_with_areas(
+        &self,
+        other: &Self,
+        self_area: InternalCalculationType,
+        other_area: InternalCalculationType,
+    ) -> f32 {
+        let si = self.intersection_area(other);
+        if si == 0 {
+            return 0.0;
+        }
+
+        unit_ratio(si as f64, self_area as f64 + other_area as f64 - si as f64)
+    }
+
+    /// Calculate a similarity score between two hotspots which penalizes both
+    /// missed and extra area, as `1 - symmetric_difference / union`.
+    ///
+    /// The symmetric difference is the area covered by exactly one of the
+    /// hotspots, which is the union minus the intersection. So this is the
+    /// same value as [`Hotspot::overlap`] (intersection over union), up to
+    /// floating point rounding. If the union has zero area this returns `0.0`.
+    pub const fn panoptic_similarity(&self, other: &Self) -> f32 {
+        let intersection = self.intersection_area(other) as f64;
+        let union = self.area() as f64 + other.area() as f64 - intersection;
+        if union == 0.0 {
+            return 0.0;
+        }
+
+        let symmetric_difference = union - intersection;
+        (1.0 - symmetric_difference / union).clamp(0.0, 1.0) as f32
+    }
+
+    /// Returns true if the [`Hotspot::overlap`] between two hotspots is
+    /// exactly the fraction `num / den`, compared using integer
+    /// cross-multiplication rather than floating point.
+    ///
+    /// Intended for tests which would otherwise need to compare `f32` values
+    /// with some tolerance, e.g. `assert!(h1.iou_eq_fraction(&h2, 1, 3))`.
+    /// Hotspots whose union has zero area have an overlap of 0, and a `den`
+    /// of 0 never matches.
+    #[cfg(feature = "test-support")]
+    pub const fn iou_eq_fraction(
+        &self,
+        other: &Self,
+        num: InternalCalculationType,
+        den: InternalCalculationType,
+    ) -> bool {
+        if den == 0 {
+            return false;
+        }
+
+        // Widen so neither the union nor the cross-multiplication can overflow.
+        let intersection = self.intersection_area(other) as u128;
+        let union = self.area() as u128 + other.area() as u128 - intersection;
+        if union == 0 {
+            return num == 0;
+        }
+
+        intersection * den as u128 == num as u128 * union
+    }
+
+    /// Calculate the % of this Hotspot that is in the other hotspot, returns an
+    /// f32 where 0 is no overlap and 1 is complete overlap.
+    ///
+    /// Differs from [`Hotspot::overlap`] in that instead of calculating the total area /
+    /// by the overlap it will return the area of self that is overlapped by
+    /// other - regardless of the remaining area of other.
+    ///
+    /// E.g.
+    /// > h1: 0,0 to 20,20 (area 400) \
+    /// > h2: 5,5 to 15,15 (area 100) \
+    /// > intersection: 5,5 to 15,15 (area 100) \
+    /// > union: 400 + 100 - 100 = 400 \
+    /// > overlap: 100 / 400 = 1.0
+    ///
+    /// This works directly on percentage hotspots too. Converting to pixels
+    /// scales every hotspot by the same factor along each axis, which doesn't
+    /// change the ratio between areas. So percentage-space containment ratios
+    /// don't depend on the image dimensions, as long as both hotspots refer to
+    /// the same image. The only drift comes from rounding each corner to the
+    /// closest stored value or pixel, so it is negligible unless the hotspots
+    /// are only a few pixels across.
+    #[inline]
+    pub const fn overlap_in(&self, other: &Self) -> f32 {
+        self.overlap_in_with_area(other, self.area())
+    }
+
+    /// Calculate [`Hotspot::overlap_in`] using a precomputed
+    /// [`Hotspot::area`] for `self`.
+    ///
+    /// Useful when comparing one hotspot against many others, so the area only
+    /// needs to be calculated once. `self_area` must be the area of `self`.
+    pub const fn overlap_in_with_area(
+        &self,
+        other: &Self,
+        self_area: InternalCalculationType,
+    ) -> f32 {
+        // Handle zero area self to avoid NaN
+        if self_area == 0 {
+            return 0.0;
+        }
+
+        // Calculate overlap % relative to self
+        unit_ratio(self.intersection_area(other) as f64, self_area as f64)
+    }
+
+    /// Calculates the highest overlap between these two hotspots by taking the maximum value
+    /// of calling [`Hotspot::overlap_in`] for each combination of self and other.
+    #[inline]
+    pub const fn max_overlap(&self, other: &Self) -> f32 {
+        self.overlap_in(other).max(other.overlap_in(self))
+    }
+
+    /// Calculate the overlap between two hotspots relative to the smaller of
+    /// the two, as a value between 0 and 1.
+    ///
+    /// This is the area of the intersection divided by the area of the smaller
+    /// hotspot, so it is 1 whenever the smaller hotspot is fully contained in
+    /// the larger one regardless of how different their sizes are (unlike
+    /// [`Hotspot::overlap`]). Mathematically this is the same value as
+    /// [`Hotspot::max_overlap`], calculated with a single division.
+    ///
+    /// If either hotspot has zero area this returns 0.
+    pub const fn min_overlap(&self, other: &Self) -> f32 {
+        let smallest_area = min!(self.area(), other.area());
+
+        if smallest_area == 0 {
+            return 0.0;
+        }
+
+        unit_ratio(self.intersection_area(other) as f64, smallest_area as f64)
+    }
+
+    /// Calculate the area of the intersection between two hotspots, 0 if they
+    /// don't overlap.
+    const fn intersection_area(&self, other: &Self) -> InternalCalculationType {
+        let width = min!(self.upper_right.x, other.upper_right.x)
+            .saturating_sub(max!(self.lower_left.x, other.lower_left.x));
+        let height = min!(self.upper_right.y, other.upper_right.y)
+            .saturating_sub(max!(self.lower_left.y, other.lower_left.y));
+
+        width as InternalCalculationType * height as InternalCalculationType
+    }
+
+    /// Returns true if the two hotspots share some area.
+    ///
+    /// Hotspots which only touch along an edge or at a corner, or which have
+    /// zero area, don't overlap. This avoids any floating point work, so is
+    /// much cheaper than [`Hotspot::overlap`] for skipping disjoint pairs.
+    #[inline]
+    pub const fn overlaps(&self, other: &Self) -> bool {
+        self.intersection_area(other) > 0
+    }
+
+    /// Returns true if `other` lies entirely within this hotspot.
+    ///
+    /// The boundary is inclusive, so a hotspot contains itself and any hotspot
+    /// sharing some of its edges. This is the exact check behind an
+    /// [`Hotspot::overlap_in`] of `1.0`, and a hotspot is always contained by
+    /// the result of [`Hotspot::combine_hotspots`].
+    #[inline]
+    pub const fn contains(&self, other: &Self) -> bool {
+        self.lower_left.x <= other.lower_left.x
+            && self.lower_left.y <= other.lower_left.y
+            && other.upper_right.x <= self.upper_right.x
+            && other.upper_right.y <= self.upper_right.y
+    }
+
+    /// Returns true if at least `fraction` of the area of `self` lies within
+    /// `other`, i.e. if [`Hotspot::overlap_in`] is at least `fraction`.
+    ///
+    /// Note the direction: this checks how much of `self` is covered by
+    /// `other`, not how much of `other` is covered by `self`. A small hotspot
+    /// inside a large one encompasses the large one with a fraction of `1.0`,
+    /// but not the other way around.
+    #[inline]
+    pub const fn encompasses(&self, other: &Self, fraction: f32) -> bool {
+        self.overlap_in(other) >= fraction
+    }
+
+    /// Returns true if these two hotspots should be treated as the same
+    /// detection, i.e. if their [`Hotspot::max_overlap`] is at least
+    /// `threshold`.
+    ///
+    /// See [`DEFAULT_MERGE_IOU`] for a sensible starting threshold.
+    #[inline]
+    pub const fn should_merge(&self, other: &Self, threshold: f32) -> bool {
+        self.max_overlap(other) >= threshold
+    }
+
+    /// Get the region covered by both this hotspot and `other`.
+    ///
+    /// Returns `None` if the two hotspots are disjoint, or if they only touch
+    /// along an edge or at a corner, rather than a zero-area hotspot.
+    #[inline]
+    pub const fn intersection(&self, other: &Self) -> Option<Self> {
+        let lower_left = Coordinate {
+            x: max!(self.lower_left.x, other.lower_left.x),
+            y: max!(self.lower_left.y, other.lower_left.y),
+        };
+        let upper_right = Coordinate {
+            x: min!(self.upper_right.x, other.upper_right.x),
+            y: min!(self.upper_right.y, other.upper_right.y),
+        };
+
+        if lower_left.x >= upper_right.x || lower_left.y >= upper_right.y {
+            return None;
+        }
+
+        Some(debug_check!(
+            Self {
+                upper_right,
+                lower_left,
+                _repr: PhantomData,
+            },
+            "Hotspot::intersection"
+        ))
+    }
+
+    /// Clip this hotspot so that it lies entirely within `bounds`.
+    ///
+    /// Returns the region of `self` which is inside `bounds`, or `None` if the
+    /// two hotspots do not overlap. This is the same as
+    /// [`Hotspot::intersection`], so clipping a hotspot to a neighbour that
+    /// shares a boundary returns `None` rather than a zero-area hotspot.
+    #[inline]
+    pub const fn clip_to_hotspot(&self, bounds: &Self) -> Option<Self> {
+        self.intersection(bounds)
+    }
+
+    /// Combines two hotspots and returns a new hotspot which will fully encompass the two provided hotspots.
+    #[inline]
+    pub const fn combine_hotspots(this: Self, other: Self) -> Self {
+        debug_check!(
+            Self {
+                upper_right: Coordinate {
+                    x: max!(this.upper_right.x, other.upper_right.x),
+                    y: max!(this.upper_right.y, other.upper_right.y),
+                },
+                lower_left: Coordinate {
+                    x: min!(this.lower_left.x, other.lower_left.x),
+                    y: min!(this.lower_left.y, other.lower_left.y),
+                },
+                _repr: PhantomData,
+            },
+            "Hotspot::combine_hotspots"
+        )
+    }
+}
+
+/// Find the index of the hotspot with the largest [`Hotspot::area`] in
+/// `hotspots`, or `None` if the slice is empty.
+///
+/// If several hotspots share the largest area the first index is returned.
+#[cfg(feature = "alloc")]
+pub fn largest<R>(hotspots: &[Hotspot<R>]) -> Option<usize> {
+    extreme_area_index(hotspots, core::cmp::Ordering::Greater)
+}
+
+/// Find the index of the hotspot with the smallest [`Hotspot::area`] in
+/// `hotspots`, or `None` if the slice is empty.
+///
+/// If several hotspots share the smallest area the first index is returned.
+#[cfg(feature = "alloc")]
+pub fn smallest<R>(hotspots: &[Hotspot<R>]) -> Option<usize> {
+    extreme_area_index(hotspots, core::cmp::Ordering::Less)
+}
+
+/// Find the first index whose area compares as `wanted` against every other.
+#[cfg(feature = "alloc")]
+fn extreme_area_index<R>(hotspots: &[Hotspot<R>], wanted: core::cmp::Ordering) -> Option<usize> {
+    (0..hotspots.len()).reduce(|best, i| {
+        if hotspots[i].cmp_by_area(&hotspots[best]) == wanted {
+            i
+        } else {
+            best
+        }
+    })
+}
+
+/// Split the span `lo..hi` into `n` consecutive parts of equal length, with any
+/// remainder added to the last part.
+#[cfg(feature = "alloc")]
+fn split_span(
+    lo: CoordinateValue,
+    hi: CoordinateValue,
+    n: u32,
+) -> impl Iterator<Item = (CoordinateValue, CoordinateValue)> {
+    // `n` may exceed the span, in which case each part but the last is empty.
+    let step = ((hi - lo) as u64).checked_div(n as u64).unwrap_or_default() as CoordinateValue;
+
+    (0..n).map(move |i| {
+        let start = lo + step * i as CoordinateValue;
+        let end = if i + 1 == n { hi } else { start + step };
+        (start, end)
+    })
+}
+
+/// Calculate the area covered by the union of `hotspots`, counting regions
+/// where they overlap only once.
+///
+/// Sweeps across the distinct x edges of the hotspots, merging the y extents
+/// of the hotspots spanning each slab. The result can't overflow because the
+/// union lies within the coordinate space.
+#[cfg(feature = "alloc")]
+fn union_area<R>(hotspots: &[Hotspot<R>]) -> InternalCalculationType {
+    let mut edges: alloc::vec::Vec<CoordinateValue> = hotspots
+        .iter()
+        .flat_map(|h| [h.lower_left.x, h.upper_right.x])
+        .collect();
+    edges.sort_unstable();
+    edges.dedup();
+
+    let mut spans = alloc::vec::Vec::with_capacity(hotspots.len());
+    let mut area = 0;
+    for slab in edges.windows(2) {
+        let &[left, right] = slab else {
+            continue;
+        };
+
+        spans.clear();
+        spans.extend(
+            hotspots
+                .iter()
+                .filter(|h| h.lower_left.x <= left && right <= h.upper_right.x)
+                .map(|h| (h.lower_left.y, h.upper_right.y)),
+        );
+        spans.sort_unstable();
+
+        let mut covered: InternalCalculationType = 0;
+        let mut current: Option<(CoordinateValue, CoordinateValue)> = None;
+        for &(lo, hi) in &spans {
+            current = match current {
+                Some((start, end)) if lo <= end => Some((start, max!(end, hi))),
+                Some((start, end)) => {
+                    covered += (end - start) as InternalCalculationType;
+                    Some((lo, hi))
+                }
+                None => Some((lo, hi)),
+            };
+        }
+        if let Some((start, end)) = current {
+            covered += (end - start) as InternalCalculationType;
+        }
+
+        area += (right - left) as InternalCalculationType * covered;
+    }
+
+    area
+}
+
+/// Formats the hotspot as `lower_left-upper_right`, with each corner
+/// formatted as in [`Coordinate`]'s `LowerHex` implementation, e.g.
+/// `000a:0014-001e:0028`.
+impl core::fmt::LowerHex for Hotspot<PixelRepr> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:x}-{:x}", self.lower_left, self.upper_right)
+    }
+}
+
+/// A builder for creating hotspots.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HotspotBuilder<R> {
+    _marker: PhantomData<R>,
+}
+
+impl core::fmt::Debug for HotspotBuilder<PixelRepr> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HotspotBuilder")
+            .field("kind", &"Pixel Representation")
+            .finish()
+    }
+}
+
+impl core::fmt::Debug for HotspotBuilder<PercentageRepr> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HotspotBuilder")
+            .field("kind", &"Percentage Representation")
+            .finish()
+    }
+}
+
+impl Hotspot {
+    /// Create a builder for a hotspot.
+    #[inline]
+    pub const fn builder() -> HotspotBuilder<PixelRepr> {
+        HotspotBuilder {
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<R: InternalRepr> HotspotBuilder<R> {
+    /// Set the internal representation for the hotspot.
+    #[inline]
+    pub const fn with_repr<NewR: InternalRepr>(self) -> HotspotBuilder<NewR> {
+        HotspotBuilder {
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+impl HotspotBuilder<PixelRepr> {
+    /// Create a pixel-based hotspot from two opposite corners, in either order.
+    ///
+    /// NOTE: we assume that these are provided with the origin in the bottom
+    /// left, as with all [`Coordinate`]s: x is horizontal (increasing to the
+    /// right) and y is vertical (increasing upwards).
+    ///
+    /// See [`HotspotBuilder::from_image_xywh`] for boxes in standard image
+    /// coordinates with the origin at the top-left.
+    #[inline]
+    pub const fn from_pixels(
+        self,
+        (Coordinate { x: x1, y: y1 }, Coordinate { x: x2, y: y2 }): (Coordinate, Coordinate),
+    ) -> Hotspot<PixelRepr> {
+        let upper_right = Coordinate {
+            x: max!(x1, x2),
+            y: max!(y1, y2),
+        };
+
+        let lower_left = Coordinate {
+            x: min!(x1, x2),
+            y: min!(y1, y2),
+        };
+
+        Hotspot {
+            upper_right,
+            lower_left,
+            _repr: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a pixel-based hotspot from a box in standard image coordinates,
+    /// as output by most object detectors and used by COCO annotations.
+    ///
+    /// Standard image coordinates have their origin at the top-left of the
+    /// image, with `x` increasing to the right and `y` increasing downwards,
+    /// and `(x, y)` is the top-left corner of a box `w` pixels wide and `h`
+    /// pixels tall. This crate places the origin at the bottom-left with `y`
+    /// increasing upwards, so the y axis is flipped using the image height,
+    /// while `x` and the width carry over unchanged. Boxes extending past the
+    /// edges of the coordinate space are clamped to them.
+    #[inline]
+    pub const fn from_image_xywh(
+        self,
+        x: CoordinateValue,
+        y: CoordinateValue,
+        w: CoordinateValue,
+        h: CoordinateValue,
+        image_dimensions: ImageDimensions,
+    ) -> Hotspot<PixelRepr> {
+        let height = image_dimensions.height;
+        self.from_pixels((
+            Coordinate {
+                x,
+                y: height.saturating_sub(y.saturating_add(h)),
+            },
+            Coordinate {
+                x: x.saturating_add(w),
+                y: height.saturating_sub(y),
+            },
+        ))
+    }
+
+    /// Create a pixel-based hotspot of the given size centred on `center`.
+    ///
+    /// The half-sizes are rounded to the closest pixel, so a box with an odd
+    /// width or height extends one pixel further below or to the left of the
+    /// center than above or to the right of it. Corners which would fall
+    /// outside the coordinate space are clamped to its edges, shrinking the
+    /// box rather than shifting it.
+    #[inline]
+    pub const fn from_center_size(
+        self,
+        center: Coordinate,
+        width: CoordinateValue,
+        height: CoordinateValue,
+    ) -> Hotspot<PixelRepr> {
+        let half_width = div_round_closest(width as InternalCalculationType, 2) as CoordinateValue;
+        let half_height =
+            div_round_closest(height as InternalCalculationType, 2) as CoordinateValue;
+
+        self.from_pixels((
+            Coordinate {
+                x: center.x.saturating_sub(half_width),
+                y: center.y.saturating_sub(half_height),
+            },
+            Coordinate {
+                x: center.x.saturating_add(width - half_width),
+                y: center.y.saturating_add(height - half_height),
+            },
+        ))
+    }
+
+    /// Create a pixel-based hotspot bounding a set of horizontal runs, e.g. as
+    /// decoded from a run-length encoded mask.
+    ///
+    /// Each run is `(y, x_start, x_end)`, and the ends of a run may be in
+    /// either order. Returns `None` if `runs` is empty.
+    pub fn from_runs(
+        self,
+        runs: &[(CoordinateValue, CoordinateValue, CoordinateValue)],
+    ) -> Option<Hotspot<PixelRepr>> {
+        runs.iter()
+            .map(|&(y, x_start, x_end)| {
+                self.from_pixels((Coordinate { x: x_start, y }, Coordinate { x: x_end, y }))
+            })
+            .reduce(Hotspot::combine_hotspots)
+    }
+}
+
+impl HotspotBuilder<PercentageRepr> {
+    /// Create a percentage-based hotspot from two opposite pixel corners, in
+    /// either order, and the image dimensions.
+    ///
+    /// Coordinates which lie outside of the image are clamped to the image
+    /// dimensions first, so the resulting hotspot never exceeds 100% of the
+    /// image.
+    #[inline]
+    pub const fn from_percentage(
+        self,
+        input: (Coordinate, Coordinate),
+        ImageDimensions { height, width }: ImageDimensions,
+    ) -> Hotspot<PercentageRepr> {
+        // Use the HotspotBuilder<PixelRepr>::from_pixel representation to handle
+        // which point is which.
+        // Clamp to the image so the percentage can't exceed 100%.
+        let Hotspot {
+            upper_right,
+            lower_left,
+            _repr: _,
+        } = Hotspot::<PixelRepr>::builder()
+            .from_pixels(input)
+            .clamp_to(ImageDimensions { width, height });
+
+        let height = height as InternalCalculationType;
+        let width = width as InternalCalculationType;
+
+        // Convert the pixel coordinates to internal percentages of the maximum possible value.
+        let upper_right = Coordinate {
+            x: div_round_closest(
+                upper_right.x as InternalCalculationType
+                    * CoordinateValue::MAX as InternalCalculationType,
+                width,
+            ) as CoordinateValue,
+            y: div_round_closest(
+                upper_right.y as InternalCalculationType
+                    * CoordinateValue::MAX as InternalCalculationType,
+                height,
+            ) as CoordinateValue,
+        };
+        let lower_left = Coordinate {
+            x: div_round_closest(
+                lower_left.x as InternalCalculationType
+                    * CoordinateValue::MAX as InternalCalculationType,
+                width,
+            ) as CoordinateValue,
+            y: div_round_closest(
+                lower_left.y as InternalCalculationType
+                    * CoordinateValue::MAX as InternalCalculationType,
+                height,
+            ) as CoordinateValue,
+        };
+
+        Hotspot {
+            upper_right,
+            lower_left,
+            _repr: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a percentage-based hotspot from corners given as fractions of the
+    /// image size between `0.0` and `1.0`, as returned by
+    /// [`Hotspot::as_ratios`].
+    ///
+    /// No image dimensions are needed. Values outside of `0.0..=1.0` are
+    /// clamped (NaN becomes `0.0`), and the corners may be provided in either
+    /// order.
+    #[inline]
+    pub const fn from_ratios(self, ll: (f32, f32), tr: (f32, f32)) -> Hotspot<PercentageRepr> {
+        let Hotspot {
+            upper_right,
+            lower_left,
+            _repr: _,
+        } = Hotspot::<PixelRepr>::builder().from_pixels((
+            Coordinate {
+                x: ratio_to_value(ll.0),
+                y: ratio_to_value(ll.1),
+            },
+            Coordinate {
+                x: ratio_to_value(tr.0),
+                y: ratio_to_value(tr.1),
+            },
+        ));
+
+        Hotspot {
+            upper_right,
+            lower_left,
+            _repr: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate alloc;
+    use alloc::format;
+
+    #[cfg(not(feature = "high_precision"))]
+    #[test]
+    fn test_percentage_repr() {
+        let hotspot = Hotspot::builder()
+            .with_repr::<PercentageRepr>()
+            .from_percentage(
+                (Coordinate { x: 50, y: 50 }, Coordinate { x: 2622, y: 2622 }),
+                crate::ImageDimensions {
+                    height: 5000,
+                    width: 5000,
+                },
+            );
+
+        assert_eq!(hotspot.upper_right, Coordinate { x: 34367, y: 34367 });
+        assert_eq!(hotspot.lower_left, Coordinate { x: 655, y: 655 });
+
+        assert_eq!(
+            hotspot.upper_right(crate::ImageDimensions {
+                height: 5000,
+                width: 5000,
+            }),
+            Coordinate { x: 2622, y: 2622 }
+        );
+
+        assert_eq!(
+            hotspot.lower_right(crate::ImageDimensions {
+                height: 10000,
+                width: 5000,
+            }),
+            Coordinate { x: 2622, y: 100 }
+        );
+
+        assert_eq!(
+            hotspot.upper_left(crate::ImageDimensions {
+                height: 5000,
+                width: 5000,
+            }),
+            Coordinate { x: 50, y: 2622 }
+        );
+
+        assert_eq!(
+            hotspot.upper_right(crate::ImageDimensions {
+                height: 10000,
+                width: 5000,
+            }),
+            Coordinate { x: 2622, y: 5244 }
+        );
+    }
+
+    #[cfg(feature = "high_precision")]
+    #[test]
+    fn test_percentage_repr() {
+        let hotspot = Hotspot::builder()
+            .with_repr::<PercentageRepr>()
+            .from_percentage(
+                (Coordinate { x: 50, y: 50 }, Coordinate { x: 2622, y: 2622 }),
+                crate::ImageDimensions {
+                    height: 5000,
+                    width: 5000,
+                },
+            );
+
+        assert_eq!(
+            hotspot.upper_right,
+            Coordinate {
+                x: 2252280849,
+                y: 2252280849
+            }
+        );
+        assert_eq!(
+            hotspot.lower_left,
+            Coordinate {
+                x: 42949673,
+                y: 42949673
+            }
+        );
+
+        assert_eq!(
+            hotspot.upper_right(crate::ImageDimensions {
+                height: 5000,
+                width: 5000,
+            }),
+            Coordinate { x: 2622, y: 2622 }
+        );
+
+        assert_eq!(
+            hotspot.lower_right(crate::ImageDimensions {
+                height: 10000,
+                width: 5000,
+            }),
+            Coordinate { x: 2622, y: 100 }
+        );
+
+        assert_eq!(
+            hotspot.upper_left(crate::ImageDimensions {
+                height: 5000,
+                width: 5000,
+            }),
+            Coordinate { x: 50, y: 2622 }
+        );
+
+        assert_eq!(
+            hotspot.upper_right(crate::ImageDimensions {
+                height: 10000,
+                width: 5000,
+            }),
+            Coordinate { x: 2622, y: 5244 }
+        );
+    }
+
+    #[test]
+    fn test_from_runs() {
+        // An L shape: a vertical bar with a foot extending to the right
+        let runs = [(10, 5, 8), (11, 5, 8), (12, 5, 20)];
+        assert_eq!(
+            Hotspot::builder().from_runs(&runs),
+            Some(make_hotspot(5, 10, 20, 12))
+        );
+
+        // Order doesn't matter, and reversed runs are normalized
+        let runs = [(12, 20, 5), (10, 5, 8), (11, 8, 5)];
+        assert_eq!(
+            Hotspot::builder().from_runs(&runs),
+            Some(make_hotspot(5, 10, 20, 12))
+        );
+
+        assert_eq!(
+            Hotspot::builder().from_runs(&[(3, 4, 4)]),
+            Some(make_hotspot(4, 3, 4, 3))
+        );
+        assert_eq!(Hotspot::builder().from_runs(&[]), None);
+    }
+
+    #[test]
+    fn test_percentage_clamped_to_image() {
+        let dims = ImageDimensions {
+            width: 100,
+            height: 200,
+        };
+        let hotspot = Hotspot::builder()
+            .with_repr::<PercentageRepr>()
+            .from_percentage(
+                (Coordinate { x: 50, y: 50 }, Coordinate { x: 150, y: 300 }),
+                dims,
+            );
+
+        assert_eq!(
+            hotspot.upper_right,
+            Coordinate {
+                x: CoordinateValue::MAX,
+                y: CoordinateValue::MAX
+            }
+        );
+        assert_eq!(hotspot.upper_right(dims), Coordinate { x: 100, y: 200 });
+        assert_eq!(hotspot.lower_left(dims), Coordinate { x: 50, y: 50 });
+
+        // Entirely outside of the image collapses onto the image edge
+        let hotspot = Hotspot::builder()
+            .with_repr::<PercentageRepr>()
+            .from_percentage(
+                (Coordinate { x: 150, y: 250 }, Coordinate { x: 160, y: 260 }),
+                dims,
+            );
+        assert_eq!(hotspot.upper_right(dims), Coordinate { x: 100, y: 200 });
+        assert_eq!(hotspot.lower_left(dims), Coordinate { x: 100, y: 200 });
+    }
+
+    #[test]
+    fn test_from_ratios() {
+        let builder = Hotspot::builder().with_repr::<PercentageRepr>();
+        let max = CoordinateValue::MAX;
+
+        let full = builder.from_ratios((0.0, 0.0), (1.0, 1.0));
+        assert_eq!(
+            full,
+            Hotspot::<PercentageRepr>::from_corners(Coordinate::ORIGIN, Coordinate::MAX)
+        );
+
+        // Round trips through as_ratios
+        let hotspot = builder.from_ratios((0.1, 0.25), (0.6, 0.9));
+        let [llx, lly, trx, try_] = hotspot.as_ratios();
+        assert_eq!(builder.from_ratios((llx, lly), (trx, try_)), hotspot);
+        for (ratio, expected) in [llx, lly, trx, try_].into_iter().zip([0.1, 0.25, 0.6, 0.9]) {
+            assert!((ratio - expected).abs() <= 1.0 / max as f32);
+        }
+
+        // Corners are normalized and out of range values clamped
+        assert_eq!(builder.from_ratios((0.6, 0.9), (0.1, 0.25)), hotspot);
+        assert_eq!(
+            builder.from_ratios((-1.0, f32::NAN), (2.0, f32::INFINITY)),
+            full
+        );
+    }
+
+    #[test]
+    fn test_as_ratios() {
+        let dims = ImageDimensions {
+            width: 1000,
+            height: 800,
+        };
+        let hotspot = Hotspot::builder()
+            .with_repr::<PercentageRepr>()
+            .from_percentage(
+                (Coordinate { x: 250, y: 200 }, Coordinate { x: 750, y: 800 }),
+                dims,
+            );
+
+        let expected = [0.25, 0.25, 0.75, 1.0];
+        for (ratio, expected) in hotspot.as_ratios().into_iter().zip(expected) {
+            // Within the precision of a stored value
+            assert!((ratio - expected).abs() <= 1.0 / CoordinateValue::MAX as f32);
+        }
+
+        let full = Hotspot::<PercentageRepr>::from_corners(Coordinate::ORIGIN, Coordinate::MAX);
+        assert_eq!(full.as_ratios(), [0.0, 0.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_as_ratios_roundtrip_within_f32_precision() {
+        // Unlike the stored integers, the `as_ratios` floats are lossy, so a
+        // round trip through them is only exact to within an f32's precision.
+        let hotspot = Hotspot::builder()
+            .with_repr::<PercentageRepr>()
+            .from_percentage(
+                (Coordinate { x: 123, y: 200 }, Coordinate { x: 987, y: 401 }),
+                ImageDimensions {
+                    width: 1000,
+                    height: 1000,
+                },
+            );
+
+        let [x1, y1, x2, y2] = hotspot.as_ratios();
+        let roundtripped = Hotspot::builder()
+            .with_repr::<PercentageRepr>()
+            .from_ratios((x1, y1), (x2, y2));
+
+        let tolerance = (CoordinateValue::MAX as f32 * f32::EPSILON) as CoordinateValue + 1;
+        for (actual, expected) in [
+            (roundtripped.lower_left, hotspot.lower_left),
+            (roundtripped.upper_right, hotspot.upper_right),
+        ] {
+            assert!(
+                actual.x.abs_diff(expected.x) <= tolerance,
+                "{actual:?} != {expected:?}"
+            );
+            assert!(
+                actual.y.abs_diff(expected.y) <= tolerance,
+                "{actual:?} != {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_translate_ratio() {
+        let dims = ImageDimensions {
+            width: 1000,
+            height: 500,
+        };
+        let hotspot = Hotspot::as_percentage(make_hotspot(100, 100, 300, 200), dims);
+
+        let shifted = hotspot.translate_ratio(0.1, -0.05);
+        assert_eq!(
+            Hotspot::as_pixels(shifted, dims),
+            make_hotspot(200, 75, 400, 175)
+        );
+        assert_eq!(
+            Hotspot::as_pixels(shifted.translate_ratio(-0.1, 0.05), dims),
+            make_hotspot(100, 100, 300, 200)
+        );
+        assert_eq!(hotspot.translate_ratio(0.0, f32::NAN), hotspot);
+
+        // Clamped against the edges of the image
+        assert_eq!(
+            Hotspot::as_pixels(hotspot.translate_ratio(0.8, -0.3), dims),
+            make_hotspot(900, 0, 1000, 50)
+        );
+        assert_eq!(
+            Hotspot::as_pixels(hotspot.translate_ratio(-1.0, 1.0), dims),
+            make_hotspot(0, 500, 0, 500)
+        );
+    }
+
+    #[test]
+    fn test_corner_ratios() {
+        let third = CoordinateValue::MAX / 3;
+        let fifth = CoordinateValue::MAX / 5;
+        let hotspot = Hotspot::<PercentageRepr>::from_corners(
+            Coordinate { x: 250, y: 0 },
+            Coordinate {
+                x: third,
+                y: CoordinateValue::MAX,
+            },
+        );
+        let ratios = hotspot.corner_ratios();
+        // 250 and MAX share a factor of 5 under both precisions
+        assert_eq!(ratios[0], (50, CoordinateValue::MAX / 5));
+        assert_eq!(ratios[2], (1, 3));
+        assert_eq!(ratios[1], (0, 1));
+        assert_eq!(ratios[3], (1, 1));
+        assert_eq!(
+            Hotspot::<PercentageRepr>::from_corners(
+                Coordinate { x: fifth, y: fifth },
+                Coordinate::MAX
+            )
+            .corner_ratios()[0],
+            (1, 5)
+        );
+
+        // Scaling each fraction back up reproduces the stored values
+        let stored = [
+            hotspot.lower_left.x,
+            hotspot.lower_left.y,
+            hotspot.upper_right.x,
+            hotspot.upper_right.y,
+        ];
+        for ((numerator, denominator), stored) in ratios.into_iter().zip(stored) {
+            assert_eq!(
+                numerator as InternalCalculationType
+                    * CoordinateValue::MAX as InternalCalculationType
+                    / denominator as InternalCalculationType,
+                stored as InternalCalculationType
+            );
+        }
+    }
+
+    fn make_hotspot(x1: u16, y1: u16, x2: u16, y2: u16) -> Hotspot<PixelRepr> {
+        Hotspot::builder().from_pixels((
+            Coordinate {
+                x: x1 as CoordinateValue,
+                y: y1 as CoordinateValue,
+            },
+            Coordinate {
+                x: x2 as CoordinateValue,
+                y: y2 as CoordinateValue,
+            },
+        ))
+    }
+
+    #[test]
+    fn test_no_overlap() {
+        let h1 = make_hotspot(0, 0, 10, 10);
+        let h2 = make_hotspot(20, 20, 30, 30);
+        assert_eq!(h1.overlap(&h2), 0.0);
+    }
+
+    #[test]
+    fn test_complete_overlap() {
+        let h1 = make_hotspot(0, 0, 10, 10);
+        let h2 = make_hotspot(0, 0, 10, 10);
+        assert_eq!(h1.overlap(&h2), 1.0);
+    }
+
+    #[test]
+    fn test_partial_overlap() {
+        // h1: 0,0 to 10,10 (area 100)
+        // h2: 5,0 to 15,10 (area 100)
+        // intersection: 5,0 to 10,10 (width 5, height 10, area 50)
+        // union: 100 + 100 - 50 = 150
+        // overlap: 50 / 150 = 1/3
+        let h1 = make_hotspot(0, 0, 10, 10);
+        let h2 = make_hotspot(5, 0, 15, 10);
+        assert!((h1.overlap(&h2) - (1.0 / 3.0)).abs() < f32::EPSILON);
+    }
+
+    #[cfg(feature = "test-support")]
+    #[test]
+    fn test_iou_eq_fraction() {
+        // Same hotspots as test_partial_overlap, 50 / 150 = 1/3 exactly
+        let h1 = make_hotspot(0, 0, 10, 10);
+        let h2 = make_hotspot(5, 0, 15, 10);
+        assert!(h1.iou_eq_fraction(&h2, 1, 3));
+        assert!(h1.iou_eq_fraction(&h2, 2, 6));
+        assert!(h2.iou_eq_fraction(&h1, 1, 3));
+        assert!(!h1.iou_eq_fraction(&h2, 33_333, 100_000));
+
+        assert!(h1.iou_eq_fraction(&h1, 1, 1));
+        assert!(h1.iou_eq_fraction(&make_hotspot(20, 20, 30, 30), 0, 1));
+        assert!(!h1.iou_eq_fraction(&h2, 1, 0));
+
+        let point = make_hotspot(5, 5, 5, 5);
+        assert!(point.iou_eq_fraction(&point, 0, 1));
+
+        let full = make_hotspot(0, 0, u16::MAX, u16::MAX);
+        assert!(full.iou_eq_fraction(&full, 1, 1));
+    }
+
+    #[test]
+    fn test_overlap_in_percentage() {
+        let pairs = [
+            (make_hotspot(0, 0, 20, 20), make_hotspot(5, 5, 15, 15)),
+            (
+                make_hotspot(100, 200, 300, 400),
+                make_hotspot(250, 150, 500, 350),
+            ),
+            (make_hotspot(10, 10, 50, 90), make_hotspot(30, 0, 70, 20)),
+            (make_hotspot(0, 0, 10, 10), make_hotspot(20, 20, 30, 30)),
+        ];
+
+        for (width, height) in [(1000, 800), (640, 640), (4000, 3000), (600, 500)] {
+            let dims = ImageDimensions { width, height };
+
+            for (a, b) in pairs {
+                let pa = Hotspot::as_percentage(a, dims);
+                let pb = Hotspot::as_percentage(b, dims);
+
+                for (percentage, pixels) in [
+                    (pa.overlap_in(&pb), a.overlap_in(&b)),
+                    (pb.overlap_in(&pa), b.overlap_in(&a)),
+                ] {
+                    assert!(
+                        (percentage - pixels).abs() < 1e-3,
+                        "{a:?} {b:?} {dims:?}: {percentage} != {pixels}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_contained_overlap() {
+        // h1: 0,0 to 20,20 (area 400)
+        // h2: 5,5 to 15,15 (area 100)
+        // intersection: 5,5 to 15,15 (area 100)
+        // union: 400 + 100 - 100 = 400
+        // overlap: 100 / 400 = 0.25
+        let h1 = make_hotspot(0, 0, 20, 20);
+        let h2 = make_hotspot(5, 5, 15, 15);
+        assert_eq!(h1.overlap(&h2), 0.25);
+
+        assert_eq!(h2.overlap_in(&h1), 1.0);
+        assert_eq!(h1.overlap_in(&h2), 0.25);
+    }
+
+    #[test]
+    fn test_corner_overlap() {
+        // h1: 0,0 to 10,10 (area 100)
+        // h2: 5,5 to 15,15 (area 100)
+        // intersection: 5,5 to 10,10 (width 5, height 5, area 25)
+        // union: 100 + 100 - 25 = 175
+        // overlap: 25 / 175 = 1/7
+        let h1 = make_hotspot(0, 0, 10, 10);
+        let h2 = make_hotspot(5, 5, 15, 15);
+        assert!((h1.overlap(&h2) - (1.0 / 7.0)).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_zero_area() {
+        let h1 = make_hotspot(0, 0, 0, 0);
+        let h2 = make_hotspot(0, 0, 10, 10);
+        assert_eq!(h1.overlap(&h2), 0.0);
+
+        let h1 = make_hotspot(0, 0, 0, 0);
+        let h2 = make_hotspot(0, 0, 0, 0);
+        assert_eq!(h1.overlap(&h2), 0.0);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let h1 = make_hotspot(0, 0, u16::MAX, u16::MAX);
+        let h2 = make_hotspot(0, 0, u16::MAX, u16::MAX);
+        assert_eq!(h1.overlap(&h2), 1.0);
+    }
+
+    #[test]
+    fn test_from_pixels_equal_coordinates() {
+        // Test when x1 == x2 and y1 == y2 (single point)
+        let h1 = make_hotspot(5, 5, 5, 5);
+        assert_eq!(h1.lower_left, Coordinate { x: 5, y: 5 });
+        assert_eq!(h1.upper_right, Coordinate { x: 5, y: 5 });
+
+        // Test when x1 == x2 but y1 != y2 (vertical line)
+        let h2 = make_hotspot(5, 0, 5, 10);
+        assert_eq!(h2.lower_left, Coordinate { x: 5, y: 0 });
+        assert_eq!(h2.upper_right, Coordinate { x: 5, y: 10 });
+
+        // Test when y1 == y2 but x1 != x2 (horizontal line)
+        let h3 = make_hotspot(0, 5, 10, 5);
+        assert_eq!(h3.lower_left, Coordinate { x: 0, y: 5 });
+        assert_eq!(h3.upper_right, Coordinate { x: 10, y: 5 });
+
+        // Test with reversed coordinates (x2 < x1, y2 < y1)
+        let h4 = make_hotspot(10, 10, 0, 0);
+        assert_eq!(h4.lower_left, Coordinate { x: 0, y: 0 });
+        assert_eq!(h4.upper_right, Coordinate { x: 10, y: 10 });
+    }
+
+    #[test]
+    fn test_from_pixels_strict_inequality_logic() {
+        // Test that the implementation uses strict < and > (not <= and >=)
+        // by verifying behavior for all orderings including adjacent values
+
+        // When x1 < x2: lower_left should get x1, upper_right should get x2
+        let h = make_hotspot(5, 7, 6, 8);
+        assert_eq!(h.lower_left.x, 5);
+        assert_eq!(h.lower_left.y, 7);
+        assert_eq!(h.upper_right.x, 6);
+        assert_eq!(h.upper_right.y, 8);
+
+        // When x1 > x2: lower_left should get x2, upper_right should get x1
+        let h = make_hotspot(10, 20, 9, 19);
+        assert_eq!(h.lower_left.x, 9);
+        assert_eq!(h.lower_left.y, 19);
+        assert_eq!(h.upper_right.x, 10);
+        assert_eq!(h.upper_right.y, 20);
+
+        // Test exhaustively with small values to cover all branches
+        for x1 in 0u16..15 {
+            for x2 in 0u16..15 {
+                let h = make_hotspot(x1, 0, x2, 0);
+
+                // Verify the correct value is in each position
+                if x1 < x2 {
+                    assert_eq!(h.lower_left.x, x1 as CoordinateValue);
+                    assert_eq!(h.upper_right.x, x2 as CoordinateValue);
+                } else {
+                    assert_eq!(h.lower_left.x, x2 as CoordinateValue);
+                    assert_eq!(h.upper_right.x, x1 as CoordinateValue);
+                }
+            }
+        }
+
+        // Same for y coordinates
+        for y1 in 0u16..15 {
+            for y2 in 0u16..15 {
+                let h = make_hotspot(0, y1, 0, y2);
+
+                if y1 < y2 {
+                    assert_eq!(h.lower_left.y, y1 as CoordinateValue);
+                    assert_eq!(h.upper_right.y, y2 as CoordinateValue);
+                } else {
+                    assert_eq!(h.lower_left.y, y2 as CoordinateValue);
+                    assert_eq!(h.upper_right.y, y1 as CoordinateValue);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_overlap_in_with_area() {
+        let h1 = make_hotspot(0, 0, 20, 20);
+        let others = [
+            make_hotspot(5, 5, 15, 15),
+            make_hotspot(10, 0, 30, 10),
+            make_hotspot(50, 50, 60, 60),
+            make_hotspot(0, 0, 20, 20),
+        ];
+
+        let area = h1.area();
+        for other in &others {
+            assert_eq!(h1.overlap_in_with_area(other, area), h1.overlap_in(other));
+            assert_eq!(
+                other.overlap_in_with_area(&h1, other.area()),
+                other.overlap_in(&h1)
+            );
+        }
+
+        let empty = make_hotspot(5, 5, 5, 5);
+        assert_eq!(empty.overlap_in_with_area(&h1, empty.area()), 0.0);
+    }
+
+    #[test]
+    fn test_max_overlap_symmetric() {
+        // Test max_overlap with identical hotspots
+        let h1 = make_hotspot(0, 0, 10, 10);
+        let h2 = make_hotspot(0, 0, 10, 10);
+        assert_eq!(h1.max_overlap(&h2), 1.0);
+    }
+
+    #[test]
+    fn test_max_overlap_no_overlap() {
+        // Test max_overlap with non-overlapping hotspots
+        let h1 = make_hotspot(0, 0, 10, 10);
+        let h2 = make_hotspot(20, 20, 30, 30);
+        assert_eq!(h1.max_overlap(&h2), 0.0);
+    }
+
+    #[test]
+    fn test_max_overlap_contained() {
+        // h1: 0,0 to 20,20 (area 400)
+        // h2: 5,5 to 15,15 (area 100)
+        // h1.overlap_in(h2) = 100/400 = 0.25
+        // h2.overlap_in(h1) = 100/100 = 1.0
+        // max_overlap should return 1.0
+        let h1 = make_hotspot(0, 0, 20, 20);
+        let h2 = make_hotspot(5, 5, 15, 15);
+        assert_eq!(h1.max_overlap(&h2), 1.0);
+    }
+
+    #[test]
+    fn test_max_overlap_partial() {
+        // h1: 0,0 to 10,10 (area 100)
+        // h2: 5,0 to 15,10 (area 100)
+        // intersection: 5,0 to 10,10 (area 50)
+        // h1.overlap_in(h2) = 50/100 = 0.5
+        // h2.overlap_in(h1) = 50/100 = 0.5
+        // max_overlap should return 0.5
+        let h1 = make_hotspot(0, 0, 10, 10);
+        let h2 = make_hotspot(5, 0, 15, 10);
+        assert_eq!(h1.max_overlap(&h2), 0.5);
+    }
+
+    #[test]
+    fn test_min_overlap() {
+        // Contained: IoU is low, but the smaller hotspot is fully covered
+        let h1 = make_hotspot(0, 0, 20, 20);
+        let h2 = make_hotspot(5, 5, 15, 15);
+        assert_eq!(h1.overlap(&h2), 0.25);
+        assert_eq!(h1.min_overlap(&h2), 1.0);
+        assert_eq!(h2.min_overlap(&h1), 1.0);
+        assert_eq!(h1.max_overlap(&h2), 1.0);
+
+        // Partial overlap with different sizes
+        // h3: 0,0 to 10,10 (area 100)
+        // h4: 5,0 to 25,10 (area 200)
+        // intersection: 5,0 to 10,10 (area 50)
+        let h3 = make_hotspot(0, 0, 10, 10);
+        let h4 = make_hotspot(5, 0, 25, 10);
+        assert_eq!(h3.overlap(&h4), 0.2);
+        assert_eq!(h3.overlap_in(&h4), 0.5);
+        assert_eq!(h4.overlap_in(&h3), 0.25);
+        assert_eq!(h3.min_overlap(&h4), 0.5);
+        assert_eq!(h3.min_overlap(&h4), h3.max_overlap(&h4));
+
+        // Zero area and disjoint
+        let empty = make_hotspot(5, 5, 5, 5);
+        assert_eq!(empty.min_overlap(&h1), 0.0);
+        assert_eq!(h1.min_overlap(&empty), 0.0);
+        assert_eq!(h3.min_overlap(&make_hotspot(50, 50, 60, 60)), 0.0);
+    }
+
+    #[cfg(feature = "high_precision")]
+    #[test]
+    fn test_overlap_high_precision_extremes() {
+        let max = CoordinateValue::MAX;
+        let full = Hotspot::builder().from_pixels((Coordinate::ORIGIN, Coordinate::MAX));
+
+        // Both spanning the full coordinate range must be exactly 1.0
+        assert_eq!(full.overlap(&full), 1.0);
+        assert_eq!(full.overlap_in(&full), 1.0);
+        assert_eq!(full.max_overlap(&full), 1.0);
+        assert_eq!(full.min_overlap(&full), 1.0);
+
+        // Areas which are indistinguishable as f32 are still distinguished
+        let shrunk = Hotspot::builder().from_pixels((Coordinate { x: 0, y: 1 }, Coordinate::MAX));
+        assert_eq!(shrunk.overlap_in(&full), 1.0);
+        assert!(full.overlap_in(&shrunk) <= 1.0);
+        assert!(full.overlap(&shrunk) <= 1.0);
+
+        let half =
+            Hotspot::builder().from_pixels((Coordinate::ORIGIN, Coordinate { x: max, y: max / 2 }));
+        assert!((full.overlap(&half) - 0.5).abs() <= f32::EPSILON);
+        assert!((full.overlap_in(&half) - 0.5).abs() <= f32::EPSILON);
+        assert_eq!(half.overlap_in(&full), 1.0);
+    }
+
+    #[cfg(feature = "rational")]
+    #[test]
+    fn test_iou_ratio() {
+        use num_rational::Ratio;
+
+        let a = make_hotspot(0, 0, 10, 10);
+        // 50 / (100 + 100 - 50) is exactly one third
+        assert_eq!(a.iou_ratio(&make_hotspot(5, 0, 15, 10)), Ratio::new(1, 3));
+        assert_eq!(*a.iou_ratio(&make_hotspot(5, 0, 15, 10)).numer(), 1);
+        assert_eq!(a.iou_ratio(&a), Ratio::new(1, 1));
+        assert_eq!(a.iou_ratio(&make_hotspot(20, 20, 30, 30)), Ratio::new(0, 1));
+
+        let point = make_hotspot(5, 5, 5, 5);
+        assert_eq!(point.iou_ratio(&point), Ratio::new(0, 1));
+
+        // Near-full boxes don't overflow the union
+        let full = make_hotspot(0, 0, u16::MAX, u16::MAX);
+        let most = make_hotspot(1, 0, u16::MAX, u16::MAX);
+        assert_eq!(
+            full.iou_ratio(&most),
+            Ratio::new(
+                u16::MAX as InternalCalculationType - 1,
+                u16::MAX as InternalCalculationType
+            )
+        );
+    }
+
+    #[test]
+    fn test_checked_overlap() {
+        let a = make_hotspot(0, 0, 10, 10);
+        let b = make_hotspot(5, 0, 15, 10);
+        assert_eq!(a.checked_overlap(&b), Some(a.overlap(&b)));
+        assert_eq!(a.checked_overlap(&make_hotspot(20, 20, 30, 30)), Some(0.0));
+
+        // Both zero-area, even when identical, is undefined
+        let point = make_hotspot(5, 5, 5, 5);
+        let line = make_hotspot(0, 5, 10, 5);
+        assert_eq!(point.checked_overlap(&point), None);
+        assert_eq!(point.checked_overlap(&line), None);
+        // But only one zero-area hotspot genuinely doesn't overlap
+        assert_eq!(point.checked_overlap(&a), Some(0.0));
+        assert_eq!(a.checked_overlap(&point), Some(0.0));
+    }
+
+    #[test]
+    fn test_panoptic_similarity() {
+        let pairs = [
+            (make_hotspot(0, 0, 20, 20), make_hotspot(5, 5, 15, 15)),
+            (make_hotspot(0, 0, 10, 10), make_hotspot(5, 0, 15, 10)),
+            (make_hotspot(0, 0, 10, 10), make_hotspot(3, 7, 19, 23)),
+            (make_hotspot(0, 0, 10, 10), make_hotspot(0, 0, 10, 10)),
+            (make_hotspot(0, 0, 10, 10), make_hotspot(20, 20, 30, 30)),
+        ];
+
+        for (a, b) in pairs {
+            let similarity = a.panoptic_similarity(&b);
+            assert!((similarity - a.overlap(&b)).abs() < 1e-6, "{a:?} {b:?}");
+            assert_eq!(similarity, b.panoptic_similarity(&a));
+        }
+
+        // 10x10 and 10x10 sharing half: union 150, symmetric difference 100
+        let (a, b) = pairs[1];
+        assert!((a.panoptic_similarity(&b) - 1.0 / 3.0).abs() < 1e-6);
+        assert_eq!(pairs[3].0.panoptic_similarity(&pairs[3].1), 1.0);
+        assert_eq!(pairs[4].0.panoptic_similarity(&pairs[4].1), 0.0);
+
+        let point = make_hotspot(5, 5, 5, 5);
+        assert_eq!(point.panoptic_similarity(&point), 0.0);
+    }
+
+    #[test]
+    fn test_contains() {
+        let outer = make_hotspot(0, 0, 100, 100);
+
+        assert!(outer.contains(&outer));
+        assert!(outer.contains(&make_hotspot(10, 10, 90, 90)));
+        // Sharing edges is still contained
+        assert!(outer.contains(&make_hotspot(0, 0, 100, 50)));
+        assert!(outer.contains(&make_hotspot(50, 50, 50, 50)));
+
+        // One corner poking out on either axis, or on both
+        assert!(!outer.contains(&make_hotspot(50, 50, 101, 90)));
+        assert!(!outer.contains(&make_hotspot(50, 50, 90, 101)));
+        assert!(!outer.contains(&make_hotspot(50, 50, 110, 110)));
+        assert!(!make_hotspot(10, 10, 90, 90).contains(&outer));
+
+        let inner = make_hotspot(120, 5, 130, 200);
+        assert!(Hotspot::combine_hotspots(outer, inner).contains(&inner));
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let h = make_hotspot(10, 10, 20, 20);
+
+        assert!(h.overlaps(&h));
+        assert!(h.overlaps(&make_hotspot(15, 15, 30, 30)));
+        assert!(h.overlaps(&make_hotspot(12, 12, 14, 14)));
+        assert!(make_hotspot(0, 0, 100, 100).overlaps(&h));
+
+        // Touching edges and corners don't count
+        assert!(!h.overlaps(&make_hotspot(20, 10, 30, 20)));
+        assert!(!h.overlaps(&make_hotspot(0, 0, 10, 10)));
+        assert!(!h.overlaps(&make_hotspot(30, 30, 40, 40)));
+        assert!(!h.overlaps(&make_hotspot(15, 15, 15, 15)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_strips() {
+        let h = make_hotspot(10, 20, 40, 43);
+
+        let strips = h.horizontal_strips(4);
+        assert_eq!(
+            strips,
+            [
+                make_hotspot(10, 20, 40, 25),
+                make_hotspot(10, 25, 40, 30),
+                make_hotspot(10, 30, 40, 35),
+                make_hotspot(10, 35, 40, 43),
+            ]
+        );
+        assert_eq!(
+            strips
+                .iter()
+                .map(Hotspot::area)
+                .sum::<InternalCalculationType>(),
+            h.area()
+        );
+        assert_eq!(union_area(&strips), h.area());
+
+        let strips = h.vertical_strips(7);
+        assert_eq!(strips.len(), 7);
+        assert_eq!(strips[0], make_hotspot(10, 20, 14, 43));
+        assert_eq!(strips[6], make_hotspot(34, 20, 40, 43));
+        for pair in strips.windows(2) {
+            assert_eq!(pair[0].upper_right().x, pair[1].lower_left().x);
+        }
+        assert_eq!(
+            strips
+                .iter()
+                .map(Hotspot::area)
+                .sum::<InternalCalculationType>(),
+            h.area()
+        );
+        assert_eq!(union_area(&strips), h.area());
+
+        assert!(h.horizontal_strips(0).is_empty());
+        assert!(h.vertical_strips(0).is_empty());
+        assert_eq!(h.horizontal_strips(1), [h]);
+        assert_eq!(h.vertical_strips(1), [h]);
+
+        // More strips than pixels
+        let strips = make_hotspot(0, 0, 10, 2).horizontal_strips(3);
+        assert_eq!(
+            strips,
+            [
+                make_hotspot(0, 0, 10, 0),
+                make_hotspot(0, 0, 10, 0),
+                make_hotspot(0, 0, 10, 2),
+            ]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_surrounding_ring() {
+        let dims = ImageDimensions {
+            width: 100,
+            height: 100,
+        };
+
+        let h = make_hotspot(40, 40, 60, 60);
+        let ring = h.surrounding_ring(5, dims);
+        assert_eq!(
+            ring,
+            [
+                make_hotspot(35, 60, 65, 65),
+                make_hotspot(35, 35, 65, 40),
+                make_hotspot(35, 40, 40, 60),
+                make_hotspot(60, 40, 65, 60),
+            ]
+        );
+        // The ring exactly covers the expanded hotspot minus the original
+        assert_eq!(union_area(&ring), 30 * 30 - 20 * 20);
+        assert!(!ring.iter().any(|border| border.overlaps(&h)));
+
+        // Touching the lower-left corner of the image omits two of the borders
+        let edge = make_hotspot(0, 0, 20, 20);
+        assert_eq!(
+            edge.surrounding_ring(5, dims),
+            [make_hotspot(0, 20, 25, 25), make_hotspot(20, 0, 25, 20)]
+        );
+
+        // Clipped near the opposite edge
+        let edge = make_hotspot(90, 40, 98, 60);
+        assert_eq!(
+            edge.surrounding_ring(5, dims)[3],
+            make_hotspot(98, 40, 100, 60)
+        );
+
+        assert!(h.surrounding_ring(0, dims).is_empty());
+        assert!(Hotspot::full(dims).surrounding_ring(5, dims).is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_union_area() {
+        assert_eq!(union_area::<PixelRepr>(&[]), 0);
+        assert_eq!(union_area(&[make_hotspot(0, 0, 10, 20)]), 200);
+
+        // Overlapping regions are only counted once
+        assert_eq!(
+            union_area(&[make_hotspot(0, 0, 10, 10), make_hotspot(5, 5, 15, 15)]),
+            175
+        );
+        assert_eq!(
+            union_area(&[make_hotspot(0, 0, 10, 10), make_hotspot(2, 2, 4, 4)]),
+            100
+        );
+        assert_eq!(
+            union_area(&[
+                make_hotspot(0, 0, 10, 10),
+                make_hotspot(0, 0, 10, 10),
+                make_hotspot(20, 0, 30, 10),
+                make_hotspot(5, 5, 5, 50),
+            ]),
+            200
+        );
+        assert_eq!(
+            union_area(&[make_hotspot(0, 0, u16::MAX, u16::MAX); 2]),
+            u16::MAX as InternalCalculationType * u16::MAX as InternalCalculationType
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_batch_to_svg() {
+        let dims = ImageDimensions {
+            width: 640,
+            height: 480,
+        };
+        let boxes = [
+            make_hotspot(10, 20, 110, 220),
+            make_hotspot(300, 0, 640, 480),
+            make_hotspot(5, 5, 5, 5),
+        ];
+
+        let svg = Hotspot::batch_to_svg(&boxes, dims);
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains("viewBox=\"0 0 640 480\""));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert_eq!(svg.matches("<rect ").count(), 3);
+
+        // Flipped so the upper edge is measured from the top of the image
+        assert!(svg.contains(
+            "<rect x=\"10\" y=\"260\" width=\"100\" height=\"200\" fill=\"none\" stroke=\"#e6194b\" />"
+        ));
+        assert!(svg.contains("<rect x=\"300\" y=\"0\" width=\"340\" height=\"480\""));
+
+        // Each rect gets a distinct color until the palette wraps
+        let many: alloc::vec::Vec<_> = (0..7).map(|i| make_hotspot(i, i, i + 1, i + 1)).collect();
+        let svg = Hotspot::batch_to_svg(&many, dims);
+        assert_eq!(svg.matches("#e6194b").count(), 2);
+        assert_eq!(svg.matches("#42d4f4").count(), 1);
+
+        assert_eq!(
+            Hotspot::batch_to_svg(&[], dims).matches("<rect ").count(),
+            0
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_merge_chain() {
+        let boxes = [
+            make_hotspot(10, 10, 20, 20),
+            make_hotspot(15, 5, 25, 18),
+            make_hotspot(30, 12, 35, 40),
+            make_hotspot(12, 11, 13, 12),
+        ];
+
+        let merged = Hotspot::merge_chain(&boxes);
+        assert_eq!(merged, Some(make_hotspot(10, 5, 35, 40)));
+
+        // Matches combining every hotspot in any order
+        let reversed = boxes
+            .iter()
+            .rev()
+            .copied()
+            .reduce(Hotspot::combine_hotspots);
+        assert_eq!(merged, reversed);
+
+        assert_eq!(Hotspot::merge_chain(&boxes[..1]), Some(boxes[0]));
+        assert_eq!(Hotspot::merge_chain(&[]), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_self_iou_matrix() {
+        let boxes = [
+            make_hotspot(0, 0, 10, 10),
+            make_hotspot(5, 0, 15, 10),
+            make_hotspot(0, 0, 20, 20),
+            make_hotspot(50, 50, 60, 60),
+            make_hotspot(2, 3, 12, 9),
+        ];
+        let n = boxes.len();
+
+        let matrix = Hotspot::self_iou_matrix(&boxes);
+        assert_eq!(matrix.len(), n * (n - 1) / 2);
+
+        // Compare against the full matrix using the documented indexing
+        for i in 0..n {
+            for j in 0..n {
+                if i < j {
+                    let index = i * n - i * (i + 1) / 2 + (j - i - 1);
+                    assert_eq!(matrix[index], boxes[i].overlap(&boxes[j]));
+                    assert_eq!(matrix[index], boxes[j].overlap(&boxes[i]));
+                }
+            }
+        }
+
+        assert!(Hotspot::self_iou_matrix(&[]).is_empty());
+        assert!(Hotspot::self_iou_matrix(&boxes[..1]).is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_match_counts() {
+        let gts = [
+            make_hotspot(0, 0, 10, 10),
+            make_hotspot(20, 0, 30, 10),
+            make_hotspot(50, 50, 60, 60),
+        ];
+        let preds = [
+            make_hotspot(0, 0, 10, 10),   // gt 0, IoU 1.0
+            make_hotspot(0, 0, 10, 5),    // gt 0, IoU 0.5, duplicate
+            make_hotspot(22, 0, 30, 10),  // gt 1, IoU 0.8
+            make_hotspot(80, 80, 90, 90), // nothing
+        ];
+
+        assert_eq!(
+            Hotspot::match_counts(&preds, &gts, &[0.5, 0.8, 0.9, 1.0]),
+            [(2, 2, 1), (2, 2, 1), (1, 3, 2), (1, 3, 2)]
+        );
+
+        // The best overlap wins, even if the duplicate comes first
+        let swapped = [preds[1], preds[0]];
+        assert_eq!(
+            Hotspot::match_counts(&swapped, &gts[..1], &[0.5]),
+            [(1, 1, 0)]
+        );
+
+        // Anything overlapping matches at a zero threshold, but disjoint pairs don't
+        assert_eq!(Hotspot::match_counts(&preds, &gts, &[0.0]), [(2, 2, 1)]);
+
+        assert_eq!(Hotspot::match_counts(&[], &gts, &[0.5]), [(0, 0, 3)]);
+        assert_eq!(Hotspot::match_counts(&preds, &[], &[0.5]), [(0, 4, 0)]);
+        assert!(Hotspot::match_counts(&preds, &gts, &[]).is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_overlap_with_union() {
+        // Two adjacent ground truth boxes, one prediction spanning both
+        let prediction = make_hotspot(0, 0, 20, 10);
+        let truth = [make_hotspot(0, 0, 10, 10), make_hotspot(10, 0, 20, 10)];
+        assert_eq!(prediction.overlap_with_union(&truth), 1.0);
+        // Against either box alone the prediction only covers half
+        assert_eq!(prediction.overlap_with_union(&truth[..1]), 0.5);
+
+        // Overlapping ground truth isn't double counted: union is 0,0 to 15,10
+        let truth = [make_hotspot(0, 0, 10, 10), make_hotspot(5, 0, 15, 10)];
+        assert_eq!(prediction.overlap_with_union(&truth), 0.75);
+
+        // Extends beyond the prediction: intersection 150, union 300
+        let truth = [make_hotspot(10, 0, 30, 10), make_hotspot(0, 0, 5, 10)];
+        assert_eq!(prediction.overlap_with_union(&truth), 0.5);
+
+        assert_eq!(prediction.overlap_with_union(&[]), 0.0);
+        assert_eq!(
+            prediction.overlap_with_union(&[make_hotspot(50, 50, 60, 60)]),
+            0.0
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_overlap_mask() {
+        let h = make_hotspot(0, 0, 10, 10);
+        let others = [
+            make_hotspot(0, 0, 10, 10),   // identical, IoU 1.0
+            make_hotspot(0, 0, 10, 5),    // IoU 0.5
+            make_hotspot(1, 0, 11, 10),   // IoU 90 / 110
+            make_hotspot(10, 0, 20, 10),  // touching edge
+            make_hotspot(50, 50, 60, 60), // disjoint
+            make_hotspot(0, 0, 100, 100), // IoU 0.01
+        ];
+
+        assert_eq!(
+            h.overlap_mask(&others, 0.5),
+            [true, false, true, false, false, false]
+        );
+        assert_eq!(
+            h.overlap_mask(&others, 0.0),
+            [true, true, true, false, false, true]
+        );
+        assert!(h.overlap_mask(&[], 0.5).is_empty());
+    }
+
+    #[test]
+    fn test_encompasses() {
+        // Half of h1 lies within h2, and a quarter of h2 lies within h1
+        let h1 = make_hotspot(0, 0, 10, 10);
+        let h2 = make_hotspot(5, 0, 25, 10);
+
+        assert!(h1.encompasses(&h2, 0.5));
+        assert!(h1.encompasses(&h2, 0.499_999));
+        assert!(!h1.encompasses(&h2, 0.500_001));
+
+        assert!(h2.encompasses(&h1, 0.25));
+        assert!(!h2.encompasses(&h1, 0.5));
+
+        // A contained hotspot is fully encompassed
+        let inner = make_hotspot(2, 2, 4, 4);
+        assert!(inner.encompasses(&h1, 1.0));
+        assert!(!h1.encompasses(&inner, 1.0));
+    }
+
+    #[test]
+    fn test_should_merge_threshold_boundary() {
+        // max_overlap is exactly 0.5, see `test_max_overlap_partial`
+        let h1 = make_hotspot(0, 0, 10, 10);
+        let h2 = make_hotspot(5, 0, 15, 10);
+        assert!(h1.should_merge(&h2, DEFAULT_MERGE_IOU));
+        assert!(h2.should_merge(&h1, DEFAULT_MERGE_IOU));
+        assert!(h1.should_merge(&h2, 0.5));
+        assert!(!h1.should_merge(&h2, 0.500_001));
+
+        let h3 = make_hotspot(20, 20, 30, 30);
+        assert!(!h1.should_merge(&h3, DEFAULT_MERGE_IOU));
+        assert!(h1.should_merge(&h3, 0.0));
+    }
+
+    #[test]
+    fn test_clip_to_hotspot() {
+        let bounds = make_hotspot(10, 10, 50, 50);
+
+        // Overhangs the bounds on the left and bottom
+        let h = make_hotspot(0, 5, 20, 30);
+        assert_eq!(
+            h.clip_to_hotspot(&bounds),
+            Some(make_hotspot(10, 10, 20, 30))
+        );
+
+        // Fully inside is unchanged, fully covering is clipped to the bounds
+        let inner = make_hotspot(20, 20, 30, 30);
+        assert_eq!(inner.clip_to_hotspot(&bounds), Some(inner));
+        let outer = make_hotspot(0, 0, 100, 100);
+        assert_eq!(outer.clip_to_hotspot(&bounds), Some(bounds));
+
+        // Disjoint and edge-touching hotspots do not overlap
+        let disjoint = make_hotspot(60, 60, 70, 70);
+        assert_eq!(disjoint.clip_to_hotspot(&bounds), None);
+        let touching = make_hotspot(50, 10, 60, 50);
+        assert_eq!(touching.clip_to_hotspot(&bounds), None);
+    }
+
+    #[test]
+    fn test_overlap_cross() {
+        let dims = ImageDimensions {
+            width: 1000,
+            height: 500,
+        };
+        let h1 = make_hotspot(0, 0, 100, 100);
+        let h2 = Hotspot::as_percentage(make_hotspot(50, 0, 150, 100), dims);
+
+        let expected = h1.overlap(&Hotspot::as_pixels(h2, dims));
+        assert_eq!(h1.overlap_cross(&h2, dims), expected);
+        assert!((h1.overlap_cross(&h2, dims) - (1.0 / 3.0)).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_from_corners() {
+        let c1 = Coordinate { x: 30, y: 10 };
+        let c2 = Coordinate { x: 10, y: 40 };
+
+        let pixels: Hotspot<PixelRepr> = Hotspot::from_corners(c1, c2);
+        assert_eq!(pixels, make_hotspot(10, 10, 30, 40));
+
+        let percentage = Hotspot::<PercentageRepr>::from_corners(c1, c2);
+        assert_eq!(percentage.lower_left, Coordinate { x: 10, y: 10 });
+        assert_eq!(percentage.upper_right, Coordinate { x: 30, y: 40 });
+
+        // Values are stored as-is
+        let dims = ImageDimensions {
+            width: CoordinateValue::MAX,
+            height: CoordinateValue::MAX,
+        };
+        assert_eq!(Hotspot::as_pixels(percentage, dims), pixels);
+    }
+
+    #[test]
+    fn test_normalize() {
+        // Deliberately inverted on both axes
+        let inverted = Hotspot::<PixelRepr> {
+            upper_right: Coordinate { x: 10, y: 20 },
+            lower_left: Coordinate { x: 30, y: 40 },
+            _repr: PhantomData,
+        };
+        assert_eq!(inverted.normalize(), make_hotspot(10, 20, 30, 40));
+
+        // Inverted on a single axis is swapped, not collapsed
+        let inverted = Hotspot::<PercentageRepr> {
+            upper_right: Coordinate { x: 30, y: 20 },
+            lower_left: Coordinate { x: 10, y: 40 },
+            _repr: PhantomData,
+        };
+        assert_eq!(
+            inverted.normalize(),
+            Hotspot::<PercentageRepr>::from_corners(
+                Coordinate { x: 10, y: 20 },
+                Coordinate { x: 30, y: 40 }
+            )
+        );
+
+        let valid = make_hotspot(1, 2, 3, 4);
+        assert_eq!(valid.normalize(), valid);
+    }
+
+    #[test]
+    fn test_clamp_to() {
+        let dims = ImageDimensions {
+            width: 100,
+            height: 50,
+        };
+
+        assert_eq!(
+            make_hotspot(10, 10, 20, 20).clamp_to(dims),
+            make_hotspot(10, 10, 20, 20)
+        );
+        assert_eq!(
+            make_hotspot(80, 40, 120, 60).clamp_to(dims),
+            make_hotspot(80, 40, 100, 50)
+        );
+        assert_eq!(
+            make_hotspot(0, 0, 500, 500).clamp_to(dims),
+            Hotspot::full(dims)
+        );
+
+        // Entirely outside on one axis collapses onto the nearest edge
+        assert_eq!(
+            make_hotspot(120, 10, 150, 20).clamp_to(dims),
+            make_hotspot(100, 10, 100, 20)
+        );
+        // And on both axes onto the nearest corner
+        assert_eq!(
+            make_hotspot(120, 60, 150, 70).clamp_to(dims),
+            make_hotspot(100, 50, 100, 50)
+        );
+
+        // Percentages of clamped hotspots stay within the image
+        let percentage = Hotspot::as_percentage(make_hotspot(80, 40, 120, 60), dims);
+        assert_eq!(
+            Hotspot::as_pixels(percentage, dims),
+            make_hotspot(80, 40, 100, 50)
+        );
+    }
+
+    #[test]
+    fn test_snap_to_image_grid() {
+        let dims = ImageDimensions {
+            width: 1000,
+            height: 1000,
+        };
+
+        // Blocks of 250 pixels
+        assert_eq!(
+            make_hotspot(130, 260, 600, 990).snap_to_image_grid(4, 4, dims),
+            make_hotspot(250, 250, 500, 1000)
+        );
+        assert_eq!(
+            make_hotspot(0, 0, 1000, 1000).snap_to_image_grid(4, 4, dims),
+            make_hotspot(0, 0, 1000, 1000)
+        );
+        // Clamped to the image first
+        assert_eq!(
+            make_hotspot(700, 10, 2000, 120).snap_to_image_grid(4, 4, dims),
+            make_hotspot(750, 0, 1000, 0)
+        );
+
+        // Uneven blocks have boundaries at 0, 33, 67 and 100
+        let uneven = ImageDimensions {
+            width: 100,
+            height: 10,
+        };
+        assert_eq!(
+            make_hotspot(20, 2, 50, 9).snap_to_image_grid(3, 0, uneven),
+            make_hotspot(33, 0, 67, 10)
+        );
+    }
+
+    #[test]
+    fn test_from_image_xywh() {
+        let dims = ImageDimensions {
+            width: 640,
+            height: 480,
+        };
+
+        // A 100x50 box 20 pixels from the left and 30 pixels from the top of
+        // the image is 400 pixels from the bottom
+        let hotspot = Hotspot::builder().from_image_xywh(20, 30, 100, 50, dims);
+        assert_eq!(hotspot, make_hotspot(20, 400, 120, 450));
+        assert_eq!(
+            (hotspot.lower_left(), hotspot.upper_right()),
+            (Coordinate { x: 20, y: 400 }, Coordinate { x: 120, y: 450 })
+        );
+        assert_eq!((hotspot.width(), hotspot.height()), (100, 50));
+
+        // The top-left corner in image coordinates, (20, 30), is the upper-left
+        // corner here, 30 pixels below the top of the image
+        assert_eq!(
+            hotspot.corners(),
+            [
+                Coordinate { x: 120, y: 450 },
+                Coordinate { x: 20, y: 450 },
+                Coordinate { x: 20, y: 400 },
+                Coordinate { x: 120, y: 400 },
+            ]
+        );
+        assert_eq!(hotspot.upper_left(), Coordinate { x: 20, y: 480 - 30 });
+        assert_eq!(
+            hotspot.lower_right(),
+            Coordinate {
+                x: 20 + 100,
+                y: 480 - 30 - 50
+            }
+        );
+
+        // The whole image
+        assert_eq!(
+            Hotspot::builder().from_image_xywh(0, 0, 640, 480, dims),
+            Hotspot::full(dims)
+        );
+        // Extending past the bottom of the image clamps to the origin
+        assert_eq!(
+            Hotspot::builder().from_image_xywh(0, 450, 10, 100, dims),
+            make_hotspot(0, 0, 10, 30)
+        );
+    }
+
+    #[test]
+    fn test_from_center_size() {
+        let center = Coordinate { x: 50, y: 40 };
+
+        // Even sizes are centred exactly
+        let hotspot = Hotspot::builder().from_center_size(center, 20, 10);
+        assert_eq!(hotspot, make_hotspot(40, 35, 60, 45));
+        assert_eq!(hotspot.center(), center);
+
+        // Odd sizes keep their size, with the extra pixel below and to the left
+        let hotspot = Hotspot::builder().from_center_size(center, 5, 3);
+        assert_eq!(hotspot, make_hotspot(47, 38, 52, 41));
+        assert_eq!((hotspot.width(), hotspot.height()), (5, 3));
+
+        // Zero-sized
+        assert_eq!(
+            Hotspot::builder().from_center_size(center, 0, 0),
+            make_hotspot(50, 40, 50, 40)
+        );
+    }
+
+    #[test]
+    fn test_from_center_size_saturates() {
+        // Near the origin, the lower-left corner clamps to 0 rather than underflowing
+        assert_eq!(
+            Hotspot::builder().from_center_size(Coordinate { x: 3, y: 1 }, 20, 10),
+            make_hotspot(0, 0, 13, 6)
+        );
+
+        // Near the maximum, the upper-right corner clamps to the maximum
+        let max = CoordinateValue::MAX;
+        let hotspot = Hotspot::builder().from_center_size(Coordinate { x: max - 2, y: max }, 10, 4);
+        assert_eq!(
+            hotspot.lower_left(),
+            Coordinate {
+                x: max - 7,
+                y: max - 2
+            }
+        );
+        assert_eq!(hotspot.upper_right(), Coordinate::MAX);
+    }
+
+    #[test]
+    fn test_labeled_corners() {
+        // Wider than it is tall, so swapped axes would give different corners
+        let h = make_hotspot(10, 20, 60, 40);
+        let mut corners = h.labeled_corners();
+
+        assert_eq!(
+            corners.next(),
+            Some((Corner::LowerLeft, Coordinate { x: 10, y: 20 }))
+        );
+        assert_eq!(
+            corners.next(),
+            Some((Corner::LowerRight, Coordinate { x: 60, y: 20 }))
+        );
+        assert_eq!(
+            corners.next(),
+            Some((Corner::UpperRight, Coordinate { x: 60, y: 40 }))
+        );
+        assert_eq!(
+            corners.next(),
+            Some((Corner::UpperLeft, Coordinate { x: 10, y: 40 }))
+        );
+        assert_eq!(corners.next(), None);
+
+        // Each label matches the accessor of the same name
+        for (label, coordinate) in h.labeled_corners() {
+            let expected = match label {
+                Corner::LowerLeft => h.lower_left(),
+                Corner::LowerRight => h.lower_right(),
+                Corner::UpperRight => h.upper_right(),
+                Corner::UpperLeft => h.upper_left(),
+            };
+            assert_eq!(coordinate, expected, "{label:?}");
+        }
+        assert!(
+            h.labeled_corners()
+                .all(|(_, corner)| h.corners().contains(&corner))
+        );
+    }
+
+    #[test]
+    fn test_into_corners() {
+        let h = make_hotspot(30, 40, 10, 20);
+        assert_eq!(
+            h.into_corners(),
+            (Coordinate { x: 10, y: 20 }, Coordinate { x: 30, y: 40 })
+        );
+        assert_eq!(Hotspot::builder().from_pixels(h.into_corners()), h);
+
+        let dims = ImageDimensions {
+            width: 100,
+            height: 50,
+        };
+        let percentage = Hotspot::as_percentage(h, dims);
+        let (lower_left, upper_right) = percentage.into_corners();
+        assert_eq!(
+            Hotspot::<PercentageRepr>::from_corners(lower_left, upper_right),
+            percentage
+        );
+        assert_eq!(
+            Hotspot::builder()
+                .with_repr::<PercentageRepr>()
+                .from_percentage(Hotspot::as_pixels(percentage, dims).into_corners(), dims),
+            percentage
+        );
+    }
+
+    #[test]
+    fn test_raw_corners() {
+        let max = CoordinateValue::MAX;
+        let dims = ImageDimensions {
+            width: 1000,
+            height: 500,
+        };
+
+        // The whole image spans the full coordinate range, whatever the dimensions
+        let percentage = Hotspot::builder()
+            .with_repr::<PercentageRepr>()
+            .from_percentage(
+                (Coordinate { x: 0, y: 500 }, Coordinate { x: 1000, y: 0 }),
+                dims,
+            );
+        assert_eq!(
+            percentage.raw_corners(),
+            [
+                Coordinate { x: max, y: max },
+                Coordinate { x: 0, y: max },
+                Coordinate { x: 0, y: 0 },
+                Coordinate { x: max, y: 0 },
+            ]
+        );
+
+        let percentage = Hotspot::<PercentageRepr>::from_corners(
+            Coordinate { x: 100, y: 200 },
+            Coordinate { x: 300, y: 400 },
+        );
+        assert_eq!(
+            percentage.raw_corners(),
+            [
+                Coordinate { x: 300, y: 400 },
+                Coordinate { x: 100, y: 400 },
+                Coordinate { x: 100, y: 200 },
+                Coordinate { x: 300, y: 200 },
+            ]
+        );
+
+        let pixels = make_hotspot(100, 200, 300, 400);
+        assert_eq!(
+            pixels.raw_corners(),
+            [
+                pixels.upper_right(),
+                pixels.upper_left(),
+                pixels.lower_left(),
+                pixels.lower_right(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_area() {
+        assert_eq!(make_hotspot(0, 0, 10, 20).area(), 200);
+        assert_eq!(make_hotspot(5, 5, 5, 10).area(), 0);
+        assert_eq!(
+            make_hotspot(0, 0, u16::MAX, u16::MAX).area(),
+            u16::MAX as InternalCalculationType * u16::MAX as InternalCalculationType
+        );
+    }
+
+    #[test]
+    fn test_width_and_height() {
+        let h = make_hotspot(3, 5, 10, 25);
+        assert_eq!((h.width(), h.height()), (7, 20));
+        assert_eq!(h.area(), 140);
+
+        let full = Hotspot::<PercentageRepr>::from_corners(Coordinate::ORIGIN, Coordinate::MAX);
+        assert_eq!(
+            (full.width(), full.height()),
+            (CoordinateValue::MAX, CoordinateValue::MAX)
+        );
+
+        const POINT: Hotspot = Hotspot::full(ImageDimensions {
+            width: 0,
+            height: 4,
+        });
+        assert_eq!((POINT.width(), POINT.height(), POINT.area()), (0, 4, 0));
+    }
+
+    #[test]
+    fn test_area_ratio() {
+        let small = make_hotspot(0, 0, 10, 10);
+        let large = make_hotspot(0, 0, 20, 20);
+        let empty = make_hotspot(5, 5, 5, 5);
+
+        assert_eq!(small.area_ratio(&small), 1.0);
+        assert_eq!(large.area_ratio(&small), 4.0);
+        assert_eq!(small.area_ratio(&large), 0.25);
+        assert_eq!(small.area_ratio(&empty), f32::INFINITY);
+        assert_eq!(empty.area_ratio(&small), 0.0);
+        assert_eq!(empty.area_ratio(&empty), 1.0);
+    }
+
+    #[test]
+    fn test_cmp_by_area() {
+        let mut boxes = [
+            make_hotspot(0, 0, 10, 10),
+            make_hotspot(0, 0, 5, 5),
+            make_hotspot(0, 0, 20, 20),
+            make_hotspot(3, 3, 3, 3),
+        ];
+        assert_eq!(
+            boxes[0].cmp_by_area(&boxes[1]),
+            core::cmp::Ordering::Greater
+        );
+        assert_eq!(
+            boxes[0].cmp_by_area(&make_hotspot(10, 10, 20, 20)),
+            core::cmp::Ordering::Equal
+        );
+
+        boxes.sort_by(|a, b| a.cmp_by_area(b));
+        assert_eq!(
+            boxes,
+            [
+                make_hotspot(3, 3, 3, 3),
+                make_hotspot(0, 0, 5, 5),
+                make_hotspot(0, 0, 10, 10),
+                make_hotspot(0, 0, 20, 20),
+            ]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_largest_and_smallest() {
+        let boxes = [
+            make_hotspot(0, 0, 10, 10),
+            make_hotspot(0, 0, 5, 5),
+            make_hotspot(0, 0, 40, 5),
+            make_hotspot(50, 50, 70, 70),
+            make_hotspot(0, 0, 2, 50),
+            make_hotspot(0, 0, 20, 20),
+        ];
+        assert_eq!(largest(&boxes), Some(3));
+        assert_eq!(smallest(&boxes), Some(1));
+
+        assert_eq!(largest::<PixelRepr>(&[]), None);
+        assert_eq!(smallest::<PixelRepr>(&[]), None);
+    }
+
+    #[test]
+    fn test_filter_min_area() {
+        let boxes = [
+            make_hotspot(0, 0, 10, 10),
+            make_hotspot(0, 0, 5, 5),
+            make_hotspot(0, 0, 20, 5),
+            make_hotspot(0, 0, 0, 0),
+        ];
+
+        let mut kept = boxes.iter().filter_map(|h| h.filter_min_area(100));
+        assert_eq!(kept.next(), Some(boxes[0]));
+        assert_eq!(kept.next(), Some(boxes[2]));
+        assert_eq!(kept.next(), None);
+
+        assert_eq!(boxes[3].filter_min_area(0), Some(boxes[3]));
+    }
+
+    #[test]
+    fn test_coordinate_constants() {
+        assert_eq!(Coordinate::ORIGIN, Coordinate { x: 0, y: 0 });
+        assert_eq!(
+            Coordinate::MAX,
+            Coordinate {
+                x: CoordinateValue::MAX,
+                y: CoordinateValue::MAX
+            }
+        );
+        assert!(Coordinate::ORIGIN < Coordinate::MAX);
+
+        assert_eq!(Coordinate::new(0, 0), Coordinate::ORIGIN);
+        assert_eq!(
+            Coordinate::try_new(CoordinateValue::MAX, CoordinateValue::MAX),
+            Ok(Coordinate::MAX)
+        );
+        const COORDINATE: Coordinate = Coordinate::new(3, 7);
+        assert_eq!((COORDINATE.x(), COORDINATE.y()), (3, 7));
+
+        assert_eq!(Coordinate::checked_new(0, 0), Some(Coordinate::ORIGIN));
+        assert_eq!(
+            Coordinate::checked_new(CoordinateValue::MAX, CoordinateValue::MAX),
+            Some(Coordinate::MAX)
+        );
+    }
+
+    #[test]
+    fn test_full() {
+        let dims = ImageDimensions {
+            width: 1920,
+            height: 1080,
+        };
+        let full = Hotspot::full(dims);
+
+        assert_eq!(full, make_hotspot(0, 0, 1920, 1080));
+        assert_eq!(full.lower_left(), Coordinate::ORIGIN);
+        assert_eq!(full.area(), 1920 * 1080);
+        assert_eq!(
+            Hotspot::as_percentage(full, dims),
+            Hotspot::<PercentageRepr>::from_corners(Coordinate::ORIGIN, Coordinate::MAX)
+        );
+
+        let empty = ImageDimensions {
+            width: 0,
+            height: 0,
+        };
+        assert_eq!(Hotspot::full(empty).area(), 0);
+    }
+
+    #[test]
+    fn test_coordinate_tuple_conversions() {
+        let coordinate = Coordinate::from((10, 20));
+        assert_eq!(coordinate, Coordinate { x: 10, y: 20 });
+
+        let (x, y): (CoordinateValue, CoordinateValue) = coordinate.into();
+        assert_eq!((x, y), (10, 20));
+        assert_eq!(
+            <(CoordinateValue, CoordinateValue)>::from(Coordinate::MAX).0,
+            CoordinateValue::MAX
+        );
+        assert_eq!(
+            Coordinate::from(<(CoordinateValue, CoordinateValue)>::from(coordinate)),
+            coordinate
+        );
+
+        let hotspot = Hotspot::builder().from_pixels(((30, 40).into(), (10, 20).into()));
+        assert_eq!(hotspot, make_hotspot(10, 20, 30, 40));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_coordinate_distance() {
+        let a = Coordinate { x: 0, y: 0 };
+        let b = Coordinate { x: 3, y: 4 };
+        assert_eq!(a.euclidean_distance(&b), 5.0);
+        assert_eq!(b.euclidean_distance(&a), 5.0);
+        assert_eq!(a.euclidean_distance(&a), 0.0);
+
+        // The squares of the separation at the maximum value don't fit in a
+        // u32 when summed
+        let max = Coordinate {
+            x: u16::MAX as CoordinateValue,
+            y: u16::MAX as CoordinateValue,
+        };
+        let expected = u16::MAX as f64 * core::f64::consts::SQRT_2;
+        assert!((Coordinate::ORIGIN.euclidean_distance(&max) as f64 - expected).abs() < 0.01);
+        assert_eq!(
+            Coordinate::ORIGIN.euclidean_distance(&Coordinate::MAX),
+            (CoordinateValue::MAX as f64 * core::f64::consts::SQRT_2) as f32
+        );
+    }
+
+    #[test]
+    fn test_manhattan_distance() {
+        let a = Coordinate { x: 1, y: 10 };
+        let b = Coordinate { x: 4, y: 6 };
+        assert_eq!(a.manhattan_distance(&b), 7);
+        assert_eq!(b.manhattan_distance(&a), 7);
+        assert_eq!(a.manhattan_distance(&a), 0);
+
+        assert_eq!(
+            Coordinate::ORIGIN.manhattan_distance(&Coordinate::MAX),
+            2 * CoordinateValue::MAX as InternalCalculationType
+        );
+    }
+
+    #[test]
+    fn test_center() {
+        assert_eq!(
+            make_hotspot(0, 0, 10, 20).center(),
+            Coordinate { x: 5, y: 10 }
+        );
+        // Even spans away from the origin have an exact center
+        assert_eq!(
+            make_hotspot(2, 3, 6, 11).center(),
+            Coordinate { x: 4, y: 7 }
+        );
+        // Rounds half-way cases up
+        assert_eq!(make_hotspot(0, 0, 5, 5).center(), Coordinate { x: 3, y: 3 });
+        assert_eq!(make_hotspot(3, 4, 8, 9).center(), Coordinate { x: 6, y: 7 });
+        // Mixed even and odd spans only round the odd axis
+        assert_eq!(
+            make_hotspot(10, 10, 14, 13).center(),
+            Coordinate { x: 12, y: 12 }
+        );
+        // Zero-sized spans are their own center
+        assert_eq!(make_hotspot(7, 9, 7, 9).center(), Coordinate { x: 7, y: 9 });
+        assert_eq!(
+            make_hotspot(0, 0, u16::MAX, u16::MAX).center(),
+            Coordinate { x: 32768, y: 32768 }
+        );
+    }
+
+    #[test]
+    fn test_center_percentage() {
+        let pixels = make_hotspot(100, 200, 333, 517);
+
+        for dims in [(1000, 800), (640, 640), (4000, 3000)] {
+            let dims = ImageDimensions {
+                width: dims.0,
+                height: dims.1,
+            };
+            let percentage = Hotspot::as_percentage(pixels, dims);
+
+            // Convert the percentage center to pixels via a zero-area hotspot
+            let center = percentage.center();
+            let center =
+                Hotspot::as_pixels(Hotspot::from_corners(center, center), dims).lower_left();
+
+            let expected = pixels.center();
+            assert!(
+                center.x.abs_diff(expected.x) <= 1,
+                "{center:?} != {expected:?}"
+            );
+            assert!(
+                center.y.abs_diff(expected.y) <= 1,
+                "{center:?} != {expected:?}"
+            );
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_centroid_distance() {
+        // Centers at (5, 5) and (35, 45)
+        let h1 = make_hotspot(0, 0, 10, 10);
+        let h2 = make_hotspot(30, 40, 40, 50);
+        assert_eq!(h1.center(), Coordinate { x: 5, y: 5 });
+        assert_eq!(h2.center(), Coordinate { x: 35, y: 45 });
+        assert_eq!(h1.centroid_distance(&h2), 50.0);
+        assert_eq!(h1.centroid_distance(&h1), 0.0);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_grow_to_aspect_within() {
+        let container = make_hotspot(0, 0, 100, 50);
+
+        // 20x10 grown to 4:1 fits within the container
+        let h = make_hotspot(40, 20, 60, 30);
+        assert_eq!(
+            h.grow_to_aspect_within(4.0, &container),
+            make_hotspot(30, 20, 70, 30)
+        );
+
+        // Shifted to stay within the container
+        let h = make_hotspot(0, 0, 20, 10);
+        assert_eq!(
+            h.grow_to_aspect_within(4.0, &container),
+            make_hotspot(0, 0, 40, 10)
+        );
+
+        // The container limits the growth in both directions
+        let h = make_hotspot(40, 20, 60, 30);
+        assert_eq!(
+            h.grow_to_aspect_within(20.0, &container),
+            make_hotspot(0, 20, 100, 30)
+        );
+        assert_eq!(
+            h.grow_to_aspect_within(0.1, &container),
+            make_hotspot(40, 0, 60, 50)
+        );
+
+        // Already at the target aspect ratio, or an invalid target
+        assert_eq!(h.grow_to_aspect_within(2.0, &container), h);
+        assert_eq!(h.grow_to_aspect_within(0.0, &container), h);
+        assert_eq!(h.grow_to_aspect_within(f32::NAN, &container), h);
+
+        // A hotspot already larger than the container is never shrunk to fit
+        let h = make_hotspot(0, 0, 110, 10);
+        assert_eq!(h.grow_to_aspect_within(20.0, &container), h);
+        let h = make_hotspot(40, 0, 100, 60);
+        assert_eq!(h.grow_to_aspect_within(0.5, &container), h);
+
+        // Only the grown side is kept within the container
+        let h = make_hotspot(10, 0, 130, 40);
+        assert_eq!(
+            h.grow_to_aspect_within(0.5, &container),
+            make_hotspot(10, 0, 130, 50)
+        );
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_scale_xy() {
+        let dims = ImageDimensions {
+            width: 100,
+            height: 100,
+        };
+        let h = make_hotspot(10, 20, 30, 40);
+
+        assert_eq!(h.scale_xy(2.0, 0.5, dims), make_hotspot(20, 10, 60, 20));
+        assert_eq!(h.scale_xy(1.0, 1.0, dims), h);
+
+        // Rounds to the closest pixel
+        assert_eq!(h.scale_xy(0.25, 0.25, dims), make_hotspot(3, 5, 8, 10));
 
-        let height = height as InternalCalculationType;
-        let width = width as InternalCalculationType;
+        // Clamped to the image
+        assert_eq!(h.scale_xy(4.0, 1.0, dims), make_hotspot(40, 20, 100, 40));
+        assert_eq!(h.scale_xy(-1.0, 1.0, dims), make_hotspot(0, 20, 0, 40));
+    }
 
-        // Convert the pixel coordinates to internal percentages of the maximum possible value.
-        let upper_right = Coordinate {
-            x: div_round_closest(
-                upper_right.x as InternalCalculationType
-                    * CoordinateValue::MAX as InternalCalculationType,
-                width,
-            ) as CoordinateValue,
-            y: div_round_closest(
-                upper_right.y as InternalCalculationType
-                    * CoordinateValue::MAX as InternalCalculationType,
-                height,
-            ) as CoordinateValue,
-        };
-        let lower_left = Coordinate {
-            x: div_round_closest(
-                lower_left.x as InternalCalculationType
-                    * CoordinateValue::MAX as InternalCalculationType,
-                width,
-            ) as CoordinateValue,
-            y: div_round_closest(
-                lower_left.y as InternalCalculationType
-                    * CoordinateValue::MAX as InternalCalculationType,
-                height,
-            ) as CoordinateValue,
-        };
+    #[test]
+    fn test_corners() {
+        let h = make_hotspot(10, 20, 30, 40);
+        assert_eq!(
+            h.corners(),
+            [
+                h.upper_right(),
+                h.upper_left(),
+                h.lower_left(),
+                h.lower_right()
+            ]
+        );
 
-        Hotspot {
-            upper_right,
-            lower_left,
-            _repr: core::marker::PhantomData,
-        }
+        // x is horizontal and y increases upwards, on a non-square hotspot
+        assert_eq!(h.upper_right(), Coordinate { x: 30, y: 40 });
+        assert_eq!(h.upper_left(), Coordinate { x: 10, y: 40 });
+        assert_eq!(h.lower_left(), Coordinate { x: 10, y: 20 });
+        assert_eq!(h.lower_right(), Coordinate { x: 30, y: 20 });
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_fold_corners() {
+        let h = make_hotspot(10, 20, 30, 40);
 
-    extern crate alloc;
-    use alloc::format;
+        let max_x = h.fold_corners(0, |max_x, corner| max!(max_x, corner.x));
+        assert_eq!(max_x, 30);
 
-    #[cfg(not(feature = "high_precision"))]
+        let visited = h.fold_corners(0, |count, _| count + 1);
+        assert_eq!(visited, 4);
+
+        let first = h.fold_corners(None, |first, corner| first.or(Some(corner)));
+        assert_eq!(first, Some(h.upper_right()));
+
+        // Visits the corners in the same order as `corners`
+        let (visited, _) = h.fold_corners(([Coordinate::ORIGIN; 4], 0), |(mut all, i), corner| {
+            all[i] = corner;
+            (all, i + 1)
+        });
+        assert_eq!(visited, h.corners());
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
     #[test]
-    fn test_percentage_repr() {
-        let hotspot = Hotspot::builder()
-            .with_repr::<PercentageRepr>()
-            .from_percentage(
-                (Coordinate { x: 50, y: 50 }, Coordinate { x: 2622, y: 2622 }),
-                crate::ImageDimensions {
-                    height: 5000,
-                    width: 5000,
-                },
-            );
+    fn test_corner_hausdorff() {
+        let h = make_hotspot(10, 20, 30, 40);
+        assert_eq!(h.corner_hausdorff(&h), 0.0);
+
+        // Offset by (3, 4), so every corner moves by 5
+        let offset = make_hotspot(13, 24, 33, 44);
+        assert_eq!(h.corner_hausdorff(&offset), 5.0);
+        assert_eq!(offset.corner_hausdorff(&h), 5.0);
+
+        // Growing one side only moves two corners
+        let wider = make_hotspot(10, 20, 36, 40);
+        assert_eq!(h.corner_hausdorff(&wider), 6.0);
+
+        // Symmetric even when one hotspot's corners are all close to the other's
+        let inner = make_hotspot(0, 0, 10, 10);
+        let outer = make_hotspot(0, 0, 100, 10);
+        assert_eq!(inner.corner_hausdorff(&outer), 90.0);
+        assert_eq!(outer.corner_hausdorff(&inner), 90.0);
+    }
 
-        assert_eq!(hotspot.upper_right, Coordinate { x: 34367, y: 34367 });
-        assert_eq!(hotspot.lower_left, Coordinate { x: 655, y: 655 });
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_expand_by_ratio() {
+        let dims = ImageDimensions {
+            width: 1000,
+            height: 1000,
+        };
+
+        let expanded = make_hotspot(100, 200, 200, 300).expand_by_ratio(0.1, dims);
+        assert_eq!(expanded, make_hotspot(90, 190, 210, 310));
+        assert_eq!((expanded.width(), expanded.height()), (120, 120));
+        assert_eq!(expanded.center(), Coordinate { x: 150, y: 250 });
 
+        // Each axis grows relative to its own size, rounding to the closest pixel
         assert_eq!(
-            hotspot.upper_right(crate::ImageDimensions {
-                height: 5000,
-                width: 5000,
-            }),
-            Coordinate { x: 2622, y: 2622 }
+            make_hotspot(100, 100, 300, 105).expand_by_ratio(0.25, dims),
+            make_hotspot(50, 99, 350, 106)
         );
 
+        // Clamped where the hotspot meets the edge of the image
         assert_eq!(
-            hotspot.lower_right(crate::ImageDimensions {
-                height: 10000,
-                width: 5000,
-            }),
-            Coordinate { x: 50, y: 5244 }
+            make_hotspot(0, 950, 100, 1000).expand_by_ratio(0.5, dims),
+            make_hotspot(0, 925, 150, 1000)
         );
 
+        let h = make_hotspot(100, 200, 200, 300);
+        assert_eq!(h.expand_by_ratio(0.0, dims), h);
+        assert_eq!(h.expand_by_ratio(-0.5, dims), h);
+        assert_eq!(h.expand_by_ratio(f32::NAN, dims), h);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_scale_about() {
+        let dims = ImageDimensions {
+            width: 100,
+            height: 100,
+        };
+        let h = make_hotspot(20, 30, 40, 50);
+
+        // Scaling about a corner keeps that corner fixed
+        let scaled = h.scale_about(h.lower_left(), 2.0, dims);
+        assert_eq!(scaled, make_hotspot(20, 30, 60, 70));
+        assert_eq!(scaled.lower_left(), h.lower_left());
+
+        let scaled = h.scale_about(h.upper_right(), 0.5, dims);
+        assert_eq!(scaled, make_hotspot(30, 40, 40, 50));
+        assert_eq!(scaled.upper_right(), h.upper_right());
+
+        // About the center grows evenly on every side
         assert_eq!(
-            hotspot.upper_left(crate::ImageDimensions {
-                height: 5000,
-                width: 5000,
-            }),
-            Coordinate { x: 2622, y: 50 }
+            h.scale_about(h.center(), 1.5, dims),
+            make_hotspot(15, 25, 45, 55)
         );
 
+        // Rounded, clamped to the image and re-normalized
         assert_eq!(
-            hotspot.upper_right(crate::ImageDimensions {
-                height: 10000,
-                width: 5000,
-            }),
-            Coordinate { x: 2622, y: 5244 }
+            h.scale_about(Coordinate { x: 0, y: 0 }, 3.0, dims),
+            make_hotspot(60, 90, 100, 100)
         );
+        assert_eq!(h.scale_about(Coordinate { x: 30, y: 40 }, -1.0, dims), h);
+        assert_eq!(h.scale_about(Coordinate { x: 50, y: 50 }, 1.0, dims), h);
     }
 
-    #[cfg(feature = "high_precision")]
+    #[cfg(any(feature = "std", feature = "libm"))]
     #[test]
-    fn test_percentage_repr() {
-        let hotspot = Hotspot::builder()
-            .with_repr::<PercentageRepr>()
-            .from_percentage(
-                (Coordinate { x: 50, y: 50 }, Coordinate { x: 2622, y: 2622 }),
-                crate::ImageDimensions {
-                    height: 5000,
-                    width: 5000,
-                },
-            );
-
-        assert_eq!(
-            hotspot.upper_right,
-            Coordinate {
-                x: 2252280849,
-                y: 2252280849
-            }
+    fn test_scale_to_area() {
+        let dims = ImageDimensions {
+            width: 100,
+            height: 100,
+        };
+        let h = make_hotspot(40, 45, 60, 55);
+
+        // Doubling the area scales each side by sqrt(2), keeping the 2:1 aspect
+        let scaled = h.scale_to_area(2 * h.area(), dims);
+        assert_eq!(scaled, make_hotspot(36, 43, 64, 57));
+        assert_eq!(scaled.center(), h.center());
+        let aspect = scaled.width() as f32 / scaled.height() as f32;
+        assert!((aspect - 2.0).abs() < 0.1, "{aspect}");
+        assert!(
+            scaled.area().abs_diff(2 * h.area())
+                < 2 * (scaled.width() + scaled.height()) as InternalCalculationType
         );
+
+        // Shrinking, and leaving the area unchanged
         assert_eq!(
-            hotspot.lower_left,
-            Coordinate {
-                x: 42949673,
-                y: 42949673
-            }
+            h.scale_to_area(h.area() / 4, dims),
+            make_hotspot(45, 48, 55, 53)
         );
+        assert_eq!(h.scale_to_area(h.area(), dims), h);
 
+        // Clamped to the image
         assert_eq!(
-            hotspot.upper_right(crate::ImageDimensions {
-                height: 5000,
-                width: 5000,
-            }),
-            Coordinate { x: 2622, y: 2622 }
+            make_hotspot(0, 0, 20, 10).scale_to_area(800, dims),
+            make_hotspot(0, 0, 30, 15)
         );
 
+        // Zero-area hotspots are returned unchanged
+        let line = make_hotspot(10, 10, 10, 20);
+        assert_eq!(line.scale_to_area(400, dims), line);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_letterbox_into() {
+        let src = ImageDimensions {
+            width: 1920,
+            height: 1080,
+        };
+        let dst = ImageDimensions {
+            width: 640,
+            height: 640,
+        };
+
+        // Scaled by 1/3 to 640x360, leaving 140px of padding above and below
+        let whole = make_hotspot(0, 0, 1920, 1080);
         assert_eq!(
-            hotspot.lower_right(crate::ImageDimensions {
-                height: 10000,
-                width: 5000,
-            }),
-            Coordinate { x: 50, y: 5244 }
+            whole.letterbox_into(src, dst),
+            make_hotspot(0, 140, 640, 500)
         );
 
+        let h = make_hotspot(960, 540, 1200, 900);
+        assert_eq!(h.letterbox_into(src, dst), make_hotspot(320, 320, 400, 440));
+
+        // Portrait images are padded on the sides instead
+        let portrait = ImageDimensions {
+            width: 1080,
+            height: 1920,
+        };
         assert_eq!(
-            hotspot.upper_left(crate::ImageDimensions {
-                height: 5000,
-                width: 5000,
-            }),
-            Coordinate { x: 2622, y: 50 }
+            make_hotspot(0, 0, 1080, 1920).letterbox_into(portrait, dst),
+            make_hotspot(140, 0, 500, 640)
         );
 
+        // Same aspect ratio is a plain resize
+        assert_eq!(h.letterbox_into(src, src), h);
+
+        let empty = ImageDimensions {
+            width: 0,
+            height: 1080,
+        };
         assert_eq!(
-            hotspot.upper_right(crate::ImageDimensions {
-                height: 10000,
-                width: 5000,
-            }),
-            Coordinate { x: 2622, y: 5244 }
+            h.letterbox_into(empty, dst),
+            make_hotspot(320, 320, 320, 320)
         );
     }
 
-    fn make_hotspot(x1: u16, y1: u16, x2: u16, y2: u16) -> Hotspot<PixelRepr> {
-        Hotspot::builder().from_pixels((
-            Coordinate {
-                x: x1 as CoordinateValue,
-                y: y1 as CoordinateValue,
-            },
-            Coordinate {
-                x: x2 as CoordinateValue,
-                y: y2 as CoordinateValue,
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_blend() {
+        let h1 = make_hotspot(0, 0, 100, 100);
+        let h2 = make_hotspot(100, 100, 200, 200);
+
+        assert_eq!(h1.blend(&h2, 0.75, 0.25), make_hotspot(25, 25, 125, 125));
+        assert_eq!(h1.blend(&h2, 3.0, 1.0), make_hotspot(25, 25, 125, 125));
+        assert_eq!(h1.blend(&h2, 1.0, 1.0), make_hotspot(50, 50, 150, 150));
+        assert_eq!(h1.blend(&h2, 1.0, 0.0), h1);
+        assert_eq!(h1.blend(&h2, 0.0, 1.0), h2);
+
+        // Invalid weights
+        assert_eq!(h1.blend(&h2, 0.0, 0.0), h1);
+        assert_eq!(h1.blend(&h2, f32::NAN, 1.0), h1);
+    }
+
+    #[test]
+    fn test_pixel_ranges() {
+        let h = make_hotspot(10, 20, 13, 25);
+        let (xs, ys) = h.pixel_ranges();
+        assert_eq!(xs, 10..=13);
+        assert_eq!(ys, 20..=25);
+
+        let count = xs.flat_map(|_| ys.clone()).count();
+        assert_eq!(count, (3 + 1) * (5 + 1));
+
+        let point = make_hotspot(5, 5, 5, 5);
+        let (xs, ys) = point.pixel_ranges();
+        assert_eq!(xs.count(), 1);
+        assert_eq!(ys.count(), 1);
+    }
+
+    #[test]
+    fn test_pixels() {
+        let h = make_hotspot(10, 20, 12, 22);
+        let mut pixels = h.pixels();
+
+        for y in 20..=22 {
+            for x in 10..=12 {
+                assert_eq!(pixels.next(), Some(Coordinate { x, y }));
+            }
+        }
+        assert_eq!(pixels.next(), None);
+
+        assert_eq!(make_hotspot(5, 5, 5, 5).pixels().count(), 1);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_circle_overlap() {
+        let h = make_hotspot(0, 0, 10, 10);
+
+        // Identical
+        assert_eq!(h.circle_overlap(&h), 1.0);
+
+        // Concentric, radii 5√2 and 2√2
+        let inner = make_hotspot(3, 3, 7, 7);
+        assert!((h.circle_overlap(&inner) - 8.0 / 50.0).abs() < 1e-6);
+        assert_eq!(h.circle_overlap(&inner), inner.circle_overlap(&h));
+
+        // Partially overlapping equal circles whose centers are one radius apart
+        let shifted = make_hotspot(5, 5, 15, 15);
+        let r2 = 50.0_f64;
+        let intersection = r2 * (2.0 * core::f64::consts::FRAC_PI_3 - 3.0_f64.sqrt() / 2.0);
+        let expected = intersection / (2.0 * core::f64::consts::PI * r2 - intersection);
+        assert!((h.circle_overlap(&shifted) as f64 - expected).abs() < 1e-6);
+
+        // Disjoint
+        let far = make_hotspot(100, 100, 110, 110);
+        assert_eq!(h.circle_overlap(&far), 0.0);
+
+        // Single points
+        let point = make_hotspot(5, 5, 5, 5);
+        assert_eq!(point.circle_overlap(&point), 0.0);
+    }
+
+    #[test]
+    fn test_contains_with_margin() {
+        let h = make_hotspot(10, 10, 20, 20);
+
+        assert!(h.contains_with_margin(Coordinate { x: 15, y: 15 }, 0));
+        assert!(h.contains_with_margin(Coordinate { x: 20, y: 10 }, 0));
+
+        // Just outside the hotspot, but within the margin
+        assert!(!h.contains_with_margin(Coordinate { x: 22, y: 15 }, 0));
+        assert!(h.contains_with_margin(Coordinate { x: 22, y: 15 }, 2));
+        assert!(h.contains_with_margin(Coordinate { x: 8, y: 8 }, 2));
+
+        // Beyond the margin
+        assert!(!h.contains_with_margin(Coordinate { x: 23, y: 15 }, 2));
+        assert!(!h.contains_with_margin(Coordinate { x: 15, y: 7 }, 2));
+
+        // Saturates at the edges of the coordinate space
+        let corner = make_hotspot(0, 0, 5, 5);
+        assert!(corner.contains_with_margin(Coordinate::ORIGIN, CoordinateValue::MAX));
+        assert!(corner.contains_with_margin(Coordinate::MAX, CoordinateValue::MAX));
+    }
+
+    #[test]
+    fn test_center_cell() {
+        // Center at (35, 72)
+        let h = make_hotspot(20, 60, 50, 84);
+        assert_eq!(h.center_cell(10), (3, 7));
+        assert_eq!(h.center_cell(32), (1, 2));
+        assert_eq!(h.center_cell(100), (0, 0));
+
+        // Centers on a cell boundary belong to the following cell
+        assert_eq!(make_hotspot(0, 0, 20, 20).center_cell(10), (1, 1));
+
+        // Zero is treated as a cell size of 1
+        assert_eq!(h.center_cell(0), (35, 72));
+        assert_eq!(h.center_cell(0), h.center_cell(1));
+    }
+
+    #[test]
+    fn test_trim() {
+        let h = make_hotspot(0, 0, 4, 4);
+
+        // The outer ring has no content
+        let trimmed = h.trim(|Coordinate { x, y }| (1..=3).contains(&x) && (1..=3).contains(&y));
+        assert_eq!(trimmed, Some(make_hotspot(1, 1, 3, 3)));
+
+        // Sparse content is bounded
+        let trimmed = h.trim(|c| c == Coordinate { x: 0, y: 3 } || c == Coordinate { x: 2, y: 1 });
+        assert_eq!(trimmed, Some(make_hotspot(0, 1, 2, 3)));
+
+        assert_eq!(h.trim(|_| true), Some(h));
+        assert_eq!(h.trim(|_| false), None);
+    }
+
+    #[cfg(feature = "debug_check")]
+    #[test]
+    fn test_debug_check_accepts_valid_corners() {
+        let h = make_hotspot(10, 20, 30, 40);
+        assert_eq!(debug_check!(h, "valid_transform"), h);
+
+        let point = make_hotspot(5, 5, 5, 5);
+        assert_eq!(debug_check!(point, "valid_transform"), point);
+    }
+
+    #[cfg(feature = "debug_check")]
+    #[test]
+    #[should_panic(expected = "`buggy_transform` produced a hotspot with inverted corners")]
+    fn test_debug_check_panics_on_inverted_corners() {
+        // A transform which forgets to re-normalize after moving a corner
+        let buggy_transform = |h: Hotspot| Hotspot {
+            lower_left: Coordinate {
+                x: h.upper_right.x + 1,
+                ..h.lower_left
             },
-        ))
+            ..h
+        };
+
+        debug_check!(
+            buggy_transform(make_hotspot(10, 20, 30, 40)),
+            "buggy_transform"
+        );
     }
 
+    #[cfg(any(feature = "std", feature = "libm"))]
     #[test]
-    fn test_no_overlap() {
-        let h1 = make_hotspot(0, 0, 10, 10);
-        let h2 = make_hotspot(20, 20, 30, 30);
-        assert_eq!(h1.overlap(&h2), 0.0);
+    fn test_aabb_f64_round_trip() {
+        let dims = ImageDimensions {
+            width: 1920,
+            height: 1080,
+        };
+        let h = make_hotspot(192, 270, 1000, 1079);
+
+        let aabb = h.as_aabb_f64(dims);
+        let expected = [0.1, 0.25, 1000.0 / 1920.0, 1079.0 / 1080.0];
+        for (value, expected) in aabb.into_iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+        assert_eq!(Hotspot::from_aabb_f64(aabb, dims), h);
+
+        assert_eq!(Hotspot::full(dims).as_aabb_f64(dims), [0.0, 0.0, 1.0, 1.0]);
+
+        // Out of range values are clamped to the image, and corners normalized
+        assert_eq!(
+            Hotspot::from_aabb_f64([1.5, 0.25, -0.5, 0.75], dims),
+            make_hotspot(0, 270, 1920, 810)
+        );
     }
 
     #[test]
-    fn test_complete_overlap() {
-        let h1 = make_hotspot(0, 0, 10, 10);
-        let h2 = make_hotspot(0, 0, 10, 10);
-        assert_eq!(h1.overlap(&h2), 1.0);
+    fn test_to_polygon_f32() {
+        let dims = ImageDimensions {
+            width: 200,
+            height: 100,
+        };
+        let h = make_hotspot(50, 25, 100, 75);
+
+        let polygon = h.to_polygon_f32(dims);
+        assert_eq!(
+            polygon,
+            [[0.25, 0.75], [0.25, 0.25], [0.5, 0.25], [0.5, 0.75]]
+        );
+
+        // Counter-clockwise, so the shoelace formula gives a positive area
+        let twice_area: f32 = (0..4)
+            .map(|i| {
+                let [x1, y1] = polygon[i];
+                let [x2, y2] = polygon[(i + 1) % 4];
+                x1 * y2 - x2 * y1
+            })
+            .sum();
+        assert!(twice_area > 0.0);
+        assert!((twice_area / 2.0 - 0.125).abs() < f32::EPSILON);
+
+        assert_eq!(
+            Hotspot::full(dims).to_polygon_f32(dims),
+            [[0.0, 1.0], [0.0, 0.0], [1.0, 0.0], [1.0, 1.0]]
+        );
     }
 
     #[test]
-    fn test_partial_overlap() {
-        // h1: 0,0 to 10,10 (area 100)
-        // h2: 5,0 to 15,10 (area 100)
-        // intersection: 5,0 to 10,10 (width 5, height 10, area 50)
-        // union: 100 + 100 - 50 = 150
-        // overlap: 50 / 150 = 1/3
-        let h1 = make_hotspot(0, 0, 10, 10);
-        let h2 = make_hotspot(5, 0, 15, 10);
-        assert!((h1.overlap(&h2) - (1.0 / 3.0)).abs() < f32::EPSILON);
+    fn test_resize_keep_center() {
+        let dims = ImageDimensions {
+            width: 100,
+            height: 80,
+        };
+
+        // Center (50, 40) is kept
+        let h = make_hotspot(40, 30, 60, 50);
+        let resized = h.resize_keep_center(30, 10, dims);
+        assert_eq!(resized, make_hotspot(35, 35, 65, 45));
+        assert_eq!(resized.center(), h.center());
+
+        // Near the image corner the size is kept by shifting inwards
+        let corner = make_hotspot(0, 0, 4, 4);
+        assert_eq!(
+            corner.resize_keep_center(20, 10, dims),
+            make_hotspot(0, 0, 20, 10)
+        );
+        let corner = make_hotspot(96, 76, 100, 80);
+        assert_eq!(
+            corner.resize_keep_center(20, 10, dims),
+            make_hotspot(80, 70, 100, 80)
+        );
+
+        // Larger than the image spans the whole image along that axis
+        assert_eq!(
+            h.resize_keep_center(500, 10, dims),
+            make_hotspot(0, 35, 100, 45)
+        );
+        assert_eq!(
+            h.resize_keep_center(0, 0, dims),
+            make_hotspot(50, 40, 50, 40)
+        );
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn test_contained_overlap() {
-        // h1: 0,0 to 20,20 (area 400)
-        // h2: 5,5 to 15,15 (area 100)
-        // intersection: 5,5 to 15,15 (area 100)
-        // union: 400 + 100 - 100 = 400
-        // overlap: 100 / 400 = 0.25
-        let h1 = make_hotspot(0, 0, 20, 20);
-        let h2 = make_hotspot(5, 5, 15, 15);
-        assert_eq!(h1.overlap(&h2), 0.25);
+    fn test_lower_hex() {
+        let coordinate = Coordinate { x: 10, y: 255 };
+        let hotspot = make_hotspot(10, 20, 30, 4096);
+
+        #[cfg(not(feature = "high_precision"))]
+        {
+            assert_eq!(alloc::format!("{coordinate:x}"), "000a:00ff");
+            assert_eq!(alloc::format!("{hotspot:x}"), "000a:0014-001e:1000");
+            assert_eq!(
+                alloc::format!(
+                    "{:x}",
+                    Coordinate {
+                        x: CoordinateValue::MAX,
+                        y: 0
+                    }
+                ),
+                "ffff:0000"
+            );
+        }
 
-        assert_eq!(h2.overlap_in(&h1), 1.0);
-        assert_eq!(h1.overlap_in(&h2), 0.25);
+        #[cfg(feature = "high_precision")]
+        {
+            assert_eq!(alloc::format!("{coordinate:x}"), "0000000a:000000ff");
+            assert_eq!(
+                alloc::format!("{hotspot:x}"),
+                "0000000a:00000014-0000001e:00001000"
+            );
+            assert_eq!(
+                alloc::format!(
+                    "{:x}",
+                    Coordinate {
+                        x: CoordinateValue::MAX,
+                        y: 0
+                    }
+                ),
+                "ffffffff:00000000"
+            );
+        }
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn test_corner_overlap() {
-        // h1: 0,0 to 10,10 (area 100)
-        // h2: 5,5 to 15,15 (area 100)
-        // intersection: 5,5 to 10,10 (width 5, height 5, area 25)
-        // union: 100 + 100 - 25 = 175
-        // overlap: 25 / 175 = 1/7
-        let h1 = make_hotspot(0, 0, 10, 10);
-        let h2 = make_hotspot(5, 5, 15, 15);
-        assert!((h1.overlap(&h2) - (1.0 / 7.0)).abs() < f32::EPSILON);
+    fn test_non_max_suppression() {
+        let boxes = [
+            make_hotspot(0, 0, 10, 10),
+            make_hotspot(1, 0, 11, 10),
+            make_hotspot(50, 50, 60, 60),
+            make_hotspot(0, 0, 9, 10),
+        ];
+        let scores = [0.6, 0.9, 0.8, 0.7];
+        // 1 suppresses 0 and 3, despite them coming first
+        assert_eq!(Hotspot::non_max_suppression(&boxes, &scores, 0.5), [1, 2]);
+        assert_eq!(
+            Hotspot::non_max_suppression(&boxes, &scores, 1.0),
+            [1, 2, 3, 0]
+        );
+
+        // A fully nested box has an IoU of 25 / 100 with the outer box
+        let nested = [make_hotspot(0, 0, 10, 10), make_hotspot(0, 0, 5, 5)];
+        assert_eq!(Hotspot::non_max_suppression(&nested, &[0.1, 0.9], 0.2), [1]);
+        assert_eq!(
+            Hotspot::non_max_suppression(&nested, &[0.1, 0.9], 0.25),
+            [1, 0]
+        );
+
+        // Boxes which only touch along an edge never suppress each other
+        let touching = [make_hotspot(0, 0, 10, 10), make_hotspot(10, 0, 20, 10)];
+        assert_eq!(
+            Hotspot::non_max_suppression(&touching, &[0.5, 0.5], 0.0),
+            [0, 1]
+        );
+        assert!(Hotspot::non_max_suppression(&[], &[], 0.5).is_empty());
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn test_zero_area() {
-        let h1 = make_hotspot(0, 0, 0, 0);
-        let h2 = make_hotspot(0, 0, 10, 10);
-        assert_eq!(h1.overlap(&h2), 0.0);
-
-        let h1 = make_hotspot(0, 0, 0, 0);
-        let h2 = make_hotspot(0, 0, 0, 0);
-        assert_eq!(h1.overlap(&h2), 0.0);
+    #[should_panic(expected = "every hotspot must have exactly one score")]
+    fn test_non_max_suppression_mismatched_scores() {
+        Hotspot::non_max_suppression(&[make_hotspot(0, 0, 10, 10)], &[], 0.5);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn test_overflow() {
-        let h1 = make_hotspot(0, 0, u16::MAX, u16::MAX);
-        let h2 = make_hotspot(0, 0, u16::MAX, u16::MAX);
-        assert_eq!(h1.overlap(&h2), 1.0);
+    fn test_merge_overlapping() {
+        // A overlaps B and B overlaps C, but A and C don't overlap
+        let a = make_hotspot(0, 0, 10, 10);
+        let b = make_hotspot(8, 0, 18, 10);
+        let c = make_hotspot(16, 0, 26, 10);
+        let far = make_hotspot(50, 50, 60, 60);
+        assert!(!a.overlaps(&c));
+
+        let expected = [make_hotspot(0, 0, 26, 10), far];
+        assert_eq!(Hotspot::merge_overlapping(&[a, b, c, far], 0.1), expected);
+        assert_eq!(Hotspot::merge_overlapping(&[far, c, a, b], 0.1), expected);
+        assert_eq!(Hotspot::merge_overlapping(&[c, far, b, a], 0.1), expected);
+
+        // Nothing overlaps enough at a higher threshold
+        assert_eq!(Hotspot::merge_overlapping(&[c, a, b], 0.5), [a, b, c]);
+        assert!(Hotspot::merge_overlapping(&[], 0.1).is_empty());
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn test_from_pixels_equal_coordinates() {
-        // Test when x1 == x2 and y1 == y2 (single point)
-        let h1 = make_hotspot(5, 5, 5, 5);
-        assert_eq!(h1.lower_left, Coordinate { x: 5, y: 5 });
-        assert_eq!(h1.upper_right, Coordinate { x: 5, y: 5 });
+    fn test_merge_overlapping_order_independent() {
+        // B lies within A and mostly within C, but A and C barely overlap.
+        // Links are only tested between the original hotspots, so every
+        // order chains all three together.
+        let a = make_hotspot(0, 0, 100, 100);
+        let b = make_hotspot(90, 0, 100, 10);
+        let c = make_hotspot(94, 0, 194, 100);
+        assert!(a.max_overlap(&c) <= 0.5);
+
+        let expected = [make_hotspot(0, 0, 194, 100)];
+        for order in [
+            [a, b, c],
+            [a, c, b],
+            [b, a, c],
+            [b, c, a],
+            [c, a, b],
+            [c, b, a],
+        ] {
+            assert_eq!(
+                Hotspot::merge_overlapping(&order, 0.5),
+                expected,
+                "{order:?}"
+            );
+        }
+    }
 
-        // Test when x1 == x2 but y1 != y2 (vertical line)
-        let h2 = make_hotspot(5, 0, 5, 10);
-        assert_eq!(h2.lower_left, Coordinate { x: 5, y: 0 });
-        assert_eq!(h2.upper_right, Coordinate { x: 5, y: 10 });
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_dedupe_by_iou() {
+        let boxes = [
+            make_hotspot(0, 0, 10, 10),
+            make_hotspot(0, 0, 10, 9),
+            make_hotspot(50, 50, 60, 60),
+            make_hotspot(1, 0, 10, 10),
+        ];
+        assert_eq!(Hotspot::dedupe_by_iou(&boxes, 0.5), [0, 2]);
+        // Nothing is similar enough at a high threshold
+        assert_eq!(Hotspot::dedupe_by_iou(&boxes, 0.95), [0, 1, 2, 3]);
+        // Identical boxes are always duplicates below an IoU of 1.0
+        assert_eq!(Hotspot::dedupe_by_iou(&[boxes[2]; 3], 0.99), [0]);
+        assert!(Hotspot::dedupe_by_iou(&[], 0.5).is_empty());
+    }
 
-        // Test when y1 == y2 but x1 != x2 (horizontal line)
-        let h3 = make_hotspot(0, 5, 10, 5);
-        assert_eq!(h3.lower_left, Coordinate { x: 0, y: 5 });
-        assert_eq!(h3.upper_right, Coordinate { x: 10, y: 5 });
+    #[test]
+    fn test_intersection() {
+        let a = make_hotspot(0, 0, 10, 10);
 
-        // Test with reversed coordinates (x2 < x1, y2 < y1)
-        let h4 = make_hotspot(10, 10, 0, 0);
-        assert_eq!(h4.lower_left, Coordinate { x: 0, y: 0 });
-        assert_eq!(h4.upper_right, Coordinate { x: 10, y: 10 });
+        assert_eq!(
+            a.intersection(&make_hotspot(5, 2, 20, 8)),
+            Some(make_hotspot(5, 2, 10, 8))
+        );
+        assert_eq!(
+            make_hotspot(5, 2, 20, 8).intersection(&a),
+            Some(make_hotspot(5, 2, 10, 8))
+        );
+        // Contained hotspots intersect to themselves
+        assert_eq!(
+            a.intersection(&make_hotspot(2, 2, 4, 4)),
+            Some(make_hotspot(2, 2, 4, 4))
+        );
+        assert_eq!(a.intersection(&a), Some(a));
+
+        // Sharing exactly one boundary line, or a single corner, is not an
+        // intersection
+        assert_eq!(a.intersection(&make_hotspot(10, 0, 20, 10)), None);
+        assert_eq!(a.intersection(&make_hotspot(0, 10, 10, 20)), None);
+        assert_eq!(a.intersection(&make_hotspot(10, 10, 20, 20)), None);
+        assert_eq!(a.intersection(&make_hotspot(50, 50, 60, 60)), None);
+        // Nor is overlapping a zero-area hotspot
+        assert_eq!(a.intersection(&make_hotspot(5, 5, 5, 5)), None);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn test_from_pixels_strict_inequality_logic() {
-        // Test that the implementation uses strict < and > (not <= and >=)
-        // by verifying behavior for all orderings including adjacent values
+    fn test_subtract() {
+        let h = make_hotspot(0, 0, 10, 10);
 
-        // When x1 < x2: lower_left should get x1, upper_right should get x2
-        let h = make_hotspot(5, 7, 6, 8);
-        assert_eq!(h.lower_left.x, 5);
-        assert_eq!(h.lower_left.y, 7);
-        assert_eq!(h.upper_right.x, 6);
-        assert_eq!(h.upper_right.y, 8);
+        // Fully covered
+        assert!(h.subtract(&make_hotspot(0, 0, 10, 10)).is_empty());
+        assert!(h.subtract(&make_hotspot(0, 0, 20, 20)).is_empty());
 
-        // When x1 > x2: lower_left should get x2, upper_right should get x1
-        let h = make_hotspot(10, 20, 9, 19);
-        assert_eq!(h.lower_left.x, 9);
-        assert_eq!(h.lower_left.y, 19);
-        assert_eq!(h.upper_right.x, 10);
-        assert_eq!(h.upper_right.y, 20);
+        // Disjoint, or only touching along an edge
+        assert_eq!(h.subtract(&make_hotspot(20, 20, 30, 30)), [h]);
+        assert_eq!(h.subtract(&make_hotspot(10, 0, 20, 10)), [h]);
 
-        // Test exhaustively with small values to cover all branches
-        for x1 in 0u16..15 {
-            for x2 in 0u16..15 {
-                let h = make_hotspot(x1, 0, x2, 0);
+        // Cutting off the upper-right corner leaves the strip below and the
+        // box to the left of the cut
+        assert_eq!(
+            h.subtract(&make_hotspot(6, 7, 20, 20)),
+            [make_hotspot(0, 0, 10, 7), make_hotspot(0, 7, 6, 10)]
+        );
 
-                // Verify the correct value is in each position
-                if x1 < x2 {
-                    assert_eq!(h.lower_left.x, x1 as CoordinateValue);
-                    assert_eq!(h.upper_right.x, x2 as CoordinateValue);
-                } else {
-                    assert_eq!(h.lower_left.x, x2 as CoordinateValue);
-                    assert_eq!(h.upper_right.x, x1 as CoordinateValue);
-                }
+        // A central hole leaves four boxes
+        let remainder = h.subtract(&make_hotspot(3, 4, 5, 6));
+        assert_eq!(
+            remainder,
+            [
+                make_hotspot(0, 6, 10, 10),
+                make_hotspot(0, 0, 10, 4),
+                make_hotspot(0, 4, 3, 6),
+                make_hotspot(5, 4, 10, 6),
+            ]
+        );
+        let total: InternalCalculationType = remainder.iter().map(Hotspot::area).sum();
+        assert_eq!(total, h.area() - 4);
+        for (i, a) in remainder.iter().enumerate() {
+            for b in &remainder[i + 1..] {
+                assert!(!a.overlaps(b));
             }
         }
+    }
 
-        // Same for y coordinates
-        for y1 in 0u16..15 {
-            for y2 in 0u16..15 {
-                let h = make_hotspot(0, y1, 0, y2);
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_partition_with() {
+        let a = make_hotspot(0, 0, 10, 10);
+        let b = make_hotspot(6, 4, 16, 8);
 
-                if y1 < y2 {
-                    assert_eq!(h.lower_left.y, y1 as CoordinateValue);
-                    assert_eq!(h.upper_right.y, y2 as CoordinateValue);
-                } else {
-                    assert_eq!(h.lower_left.y, y2 as CoordinateValue);
-                    assert_eq!(h.upper_right.y, y1 as CoordinateValue);
-                }
+        let (intersection, a_only, b_only) = a.partition_with(&b);
+        let intersection = intersection.unwrap();
+        assert_eq!(intersection, make_hotspot(6, 4, 10, 8));
+        assert_eq!(
+            a_only,
+            [
+                make_hotspot(0, 8, 10, 10),
+                make_hotspot(0, 0, 10, 4),
+                make_hotspot(0, 4, 6, 8),
+            ]
+        );
+        assert_eq!(b_only, [make_hotspot(10, 4, 16, 8)]);
+
+        // Each original is exactly covered by the intersection and its own parts
+        for (original, parts) in [(a, &a_only), (b, &b_only)] {
+            let covering = parts.iter().fold(intersection, |acc, part| {
+                Hotspot::combine_hotspots(acc, *part)
+            });
+            assert_eq!(covering, original);
+            let total: InternalCalculationType = parts.iter().map(Hotspot::area).sum();
+            assert_eq!(total + intersection.area(), original.area());
+            for part in parts.iter() {
+                assert!(!part.overlaps(&intersection));
             }
         }
+
+        // Disjoint hotspots are returned whole
+        let c = make_hotspot(20, 20, 30, 30);
+        assert_eq!(a.partition_with(&c), (None, alloc::vec![a], alloc::vec![c]));
     }
 
     #[test]
-    fn test_max_overlap_symmetric() {
-        // Test max_overlap with identical hotspots
-        let h1 = make_hotspot(0, 0, 10, 10);
-        let h2 = make_hotspot(0, 0, 10, 10);
-        assert_eq!(h1.max_overlap(&h2), 1.0);
+    fn test_contains_point() {
+        let h = make_hotspot(10, 20, 30, 40);
+
+        assert!(h.contains_point(Coordinate { x: 15, y: 25 }));
+        // The boundary is inclusive, including the corners
+        assert!(h.contains_point(Coordinate { x: 10, y: 30 }));
+        assert!(h.contains_point(Coordinate { x: 30, y: 40 }));
+        assert!(h.contains_point(Coordinate { x: 10, y: 20 }));
+
+        assert!(!h.contains_point(Coordinate { x: 9, y: 25 }));
+        assert!(!h.contains_point(Coordinate { x: 31, y: 25 }));
+        assert!(!h.contains_point(Coordinate { x: 15, y: 19 }));
+        assert!(!h.contains_point(Coordinate { x: 15, y: 41 }));
+
+        let dims = ImageDimensions {
+            width: 200,
+            height: 100,
+        };
+        let percentage = Hotspot::as_percentage(h, dims);
+        assert!(percentage.contains_point(Coordinate { x: 30, y: 40 }, dims));
+        assert!(!percentage.contains_point(Coordinate { x: 31, y: 40 }, dims));
+        // The same hotspot covers more pixels in a larger image
+        let larger = ImageDimensions {
+            width: 400,
+            height: 200,
+        };
+        assert!(percentage.contains_point(Coordinate { x: 60, y: 80 }, larger));
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn test_max_overlap_no_overlap() {
-        // Test max_overlap with non-overlapping hotspots
-        let h1 = make_hotspot(0, 0, 10, 10);
-        let h2 = make_hotspot(20, 20, 30, 30);
-        assert_eq!(h1.max_overlap(&h2), 0.0);
+    fn test_coverage_grid() {
+        let dims = ImageDimensions {
+            width: 40,
+            height: 25,
+        };
+        let boxes = [make_hotspot(0, 0, 20, 20), make_hotspot(10, 10, 30, 30)];
+
+        // 4 columns and 3 rows, the last row only 5 pixels tall
+        #[rustfmt::skip]
+        assert_eq!(
+            Hotspot::coverage_grid(&boxes, dims, 10),
+            [
+                1, 1, 0, 0,
+                1, 2, 1, 0,
+                0, 1, 1, 0,
+            ]
+        );
+
+        // Zero-area boxes, and boxes outside of the image, aren't counted
+        let ignored = [make_hotspot(5, 5, 5, 15), make_hotspot(40, 0, 50, 10)];
+        assert_eq!(Hotspot::coverage_grid(&ignored, dims, 10), [0; 12]);
+        assert!(
+            Hotspot::coverage_grid(
+                &boxes,
+                ImageDimensions {
+                    width: 0,
+                    height: 0,
+                },
+                10
+            )
+            .is_empty()
+        );
     }
 
     #[test]
-    fn test_max_overlap_contained() {
-        // h1: 0,0 to 20,20 (area 400)
-        // h2: 5,5 to 15,15 (area 100)
-        // h1.overlap_in(h2) = 100/400 = 0.25
-        // h2.overlap_in(h1) = 100/100 = 1.0
-        // max_overlap should return 1.0
-        let h1 = make_hotspot(0, 0, 20, 20);
-        let h2 = make_hotspot(5, 5, 15, 15);
-        assert_eq!(h1.max_overlap(&h2), 1.0);
+    fn test_inflate() {
+        let h = make_hotspot(10, 20, 30, 40);
+        assert_eq!(h.inflate(5), make_hotspot(5, 15, 35, 45));
+        assert_eq!(h.inflate(0), h);
+
+        // Saturates at both bounds
+        assert_eq!(h.inflate(15), make_hotspot(0, 5, 45, 55));
+        let max = CoordinateValue::MAX;
+        let near_max = Hotspot::<PixelRepr>::from_corners(
+            Coordinate {
+                x: max - 10,
+                y: max - 20,
+            },
+            Coordinate { x: max - 5, y: max },
+        );
+        assert_eq!(
+            near_max.inflate(8),
+            Hotspot::from_corners(
+                Coordinate {
+                    x: max - 18,
+                    y: max - 28,
+                },
+                Coordinate::MAX
+            )
+        );
+        assert_eq!(
+            h.inflate(max),
+            Hotspot::from_corners(Coordinate::ORIGIN, Coordinate::MAX)
+        );
     }
 
     #[test]
-    fn test_max_overlap_partial() {
-        // h1: 0,0 to 10,10 (area 100)
-        // h2: 5,0 to 15,10 (area 100)
-        // intersection: 5,0 to 10,10 (area 50)
-        // h1.overlap_in(h2) = 50/100 = 0.5
-        // h2.overlap_in(h1) = 50/100 = 0.5
-        // max_overlap should return 0.5
-        let h1 = make_hotspot(0, 0, 10, 10);
-        let h2 = make_hotspot(5, 0, 15, 10);
-        assert_eq!(h1.max_overlap(&h2), 0.5);
+    fn test_translate() {
+        let h = make_hotspot(10, 20, 30, 40);
+        assert_eq!(h.translate(5, -5), make_hotspot(15, 15, 35, 35));
+        assert_eq!(h.translate(0, 0), h);
+        assert_eq!(h.translate(5, -5).translate(-5, 5), h);
+
+        // Partially clipping against the lower edges shrinks the hotspot
+        assert_eq!(h.translate(-15, 0), make_hotspot(0, 20, 15, 40));
+        assert_eq!(h.translate(0, -30), make_hotspot(10, 0, 30, 10));
+        // Fully past the lower edges collapses to zero size
+        assert_eq!(h.translate(-30, 0), make_hotspot(0, 20, 0, 40));
+        assert_eq!(h.translate(i32::MIN, i32::MIN), make_hotspot(0, 0, 0, 0));
+
+        // And likewise against the upper edges
+        let max = CoordinateValue::MAX;
+        let near_max = |x1, y1, x2, y2| {
+            Hotspot::<PixelRepr>::from_corners(
+                Coordinate {
+                    x: max - x1,
+                    y: max - y1,
+                },
+                Coordinate {
+                    x: max - x2,
+                    y: max - y2,
+                },
+            )
+        };
+        let h = near_max(30, 40, 10, 20);
+        assert_eq!(h.translate(15, 0), near_max(15, 40, 0, 20));
+        assert_eq!(h.translate(0, 25), near_max(30, 15, 10, 0));
+        assert_eq!(h.translate(30, 0), near_max(0, 40, 0, 20));
+        assert_eq!(h.translate(i32::MAX, i32::MAX), near_max(0, 0, 0, 0));
+    }
+
+    #[test]
+    fn test_deflate() {
+        let h = make_hotspot(10, 20, 30, 40);
+        assert_eq!(h.deflate(5), make_hotspot(15, 25, 25, 35));
+        assert_eq!(h.deflate(5).inflate(5), h);
+        // Exactly half collapses to the center without inverting
+        assert_eq!(h.deflate(10), make_hotspot(20, 30, 20, 30));
+
+        // Over-deflating collapses to a zero-area box at the center
+        assert_eq!(h.deflate(11), make_hotspot(20, 30, 20, 30));
+        assert_eq!(
+            h.deflate(CoordinateValue::MAX),
+            make_hotspot(20, 30, 20, 30)
+        );
+
+        // Each axis collapses independently
+        let wide = make_hotspot(0, 0, 100, 10);
+        assert_eq!(wide.deflate(8), make_hotspot(8, 5, 92, 5));
+    }
+
+    #[test]
+    fn test_edge_distance() {
+        let h = make_hotspot(0, 0, 20, 10);
+
+        // The center is closest to the top and bottom edges
+        assert_eq!(h.edge_distance(Coordinate { x: 10, y: 5 }), Some(5.0));
+        // Near the right edge
+        assert_eq!(h.edge_distance(Coordinate { x: 18, y: 5 }), Some(2.0));
+        assert_eq!(h.edge_distance(Coordinate { x: 20, y: 5 }), Some(0.0));
+        assert_eq!(h.edge_distance(Coordinate { x: 3, y: 9 }), Some(1.0));
+
+        assert_eq!(h.edge_distance(Coordinate { x: 21, y: 5 }), None);
+        assert_eq!(h.edge_distance(Coordinate { x: 5, y: 11 }), None);
+    }
+
+    #[test]
+    fn test_step_toward() {
+        let target = make_hotspot(50, 40, 60, 100);
+        let mut h = make_hotspot(0, 0, 20, 20);
+
+        h = h.step_toward(&target, 15);
+        assert_eq!(h, make_hotspot(15, 15, 35, 35));
+        h = h.step_toward(&target, 15);
+        assert_eq!(h, make_hotspot(30, 30, 50, 50));
+        // Corners within the step snap to the target
+        h = h.step_toward(&target, 15);
+        assert_eq!(h, make_hotspot(45, 40, 60, 65));
+
+        for _ in 0..10 {
+            h = h.step_toward(&target, 15);
+        }
+        assert_eq!(h, target);
+        assert_eq!(target.step_toward(&target, 15), target);
+
+        // Shrinking towards a smaller target never inverts the corners
+        let small = make_hotspot(10, 10, 12, 12);
+        assert_eq!(
+            make_hotspot(0, 0, 100, 100).step_toward(&small, 60),
+            make_hotspot(10, 10, 40, 40)
+        );
+        assert_eq!(h.step_toward(&small, 0), h);
+    }
+
+    #[test]
+    fn test_largest_inscribed_square() {
+        let h = make_hotspot(0, 0, 10, 20);
+        let square = h.largest_inscribed_square();
+        assert_eq!(square, make_hotspot(0, 5, 10, 15));
+        assert_eq!(square.center(), h.center());
+
+        assert_eq!(
+            make_hotspot(10, 10, 40, 20).largest_inscribed_square(),
+            make_hotspot(20, 10, 30, 20)
+        );
+        // Odd difference leaves the extra pixel on the upper side
+        assert_eq!(
+            make_hotspot(0, 0, 5, 2).largest_inscribed_square(),
+            make_hotspot(1, 0, 3, 2)
+        );
+
+        let square = make_hotspot(5, 5, 15, 15);
+        assert_eq!(square.largest_inscribed_square(), square);
+        let line = make_hotspot(5, 5, 15, 5);
+        assert_eq!(line.largest_inscribed_square(), make_hotspot(10, 5, 10, 5));
+        let point = make_hotspot(5, 5, 5, 5);
+        assert_eq!(point.largest_inscribed_square(), point);
     }
 
     #[test]
@@ -875,6 +5721,260 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_contains_all_and_any() {
+        let h = make_hotspot(10, 10, 20, 20);
+
+        let inside = [Coordinate { x: 10, y: 10 }, Coordinate { x: 15, y: 20 }];
+        let outside = [Coordinate { x: 9, y: 15 }, Coordinate { x: 15, y: 21 }];
+        let mixed = [
+            Coordinate { x: 15, y: 15 },
+            Coordinate { x: 25, y: 15 },
+            Coordinate { x: 20, y: 20 },
+        ];
+
+        assert!(h.contains_all(&inside));
+        assert!(h.contains_any(&inside));
+
+        assert!(!h.contains_all(&outside));
+        assert!(!h.contains_any(&outside));
+
+        assert!(!h.contains_all(&mixed));
+        assert!(h.contains_any(&mixed));
+
+        // Empty slices
+        assert!(h.contains_all(&[]));
+        assert!(!h.contains_any(&[]));
+    }
+
+    #[cfg(not(feature = "high_precision"))]
+    #[test]
+    fn test_pack_u64_roundtrip() {
+        let h = make_hotspot(1, 2, 3, 4);
+        assert_eq!(h.pack_u64(), 0x0003_0004_0001_0002);
+        assert_eq!(Hotspot::from_u64(h.pack_u64()), h);
+
+        let h = make_hotspot(0, 0, u16::MAX, u16::MAX);
+        assert_eq!(Hotspot::from_u64(h.pack_u64()), h);
+
+        let h = make_hotspot(0, 0, 0, 0);
+        assert_eq!(h.pack_u64(), 0);
+        assert_eq!(Hotspot::from_u64(0), h);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_csv_line() {
+        let h = make_hotspot(10, 20, 30, 40);
+        assert_eq!(h.to_csv_line(), "10,20,30,40");
+        assert_eq!(Hotspot::from_csv_line(&h.to_csv_line()), Ok(h));
+
+        // Whitespace is ignored and corners are normalized
+        assert_eq!(Hotspot::from_csv_line(" 30, 40 ,10,20 "), Ok(h));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_csv_line_invalid() {
+        assert_eq!(
+            Hotspot::from_csv_line("10,20,30"),
+            Err(HotspotError::InvalidFieldCount {
+                expected: 4,
+                found: 3
+            })
+        );
+        assert_eq!(
+            Hotspot::from_csv_line("10,20,30,40,50"),
+            Err(HotspotError::InvalidFieldCount {
+                expected: 4,
+                found: 5
+            })
+        );
+        assert!(matches!(
+            Hotspot::from_csv_line("10,20,abc,40"),
+            Err(HotspotError::InvalidCoordinate(_))
+        ));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_subdivide_until() {
+        let h = make_hotspot(0, 0, 100, 60);
+        let leaves = h.subdivide_until(500);
+
+        assert!(leaves.iter().all(|leaf| leaf.area() <= 500));
+        assert!(
+            leaves
+                .iter()
+                .all(|leaf| h.clip_to_hotspot(leaf) == Some(*leaf))
+        );
+        assert_eq!(
+            leaves
+                .iter()
+                .map(Hotspot::area)
+                .sum::<InternalCalculationType>(),
+            h.area()
+        );
+        for (i, a) in leaves.iter().enumerate() {
+            for b in leaves.iter().skip(i + 1) {
+                assert_eq!(a.overlap(b), 0.0);
+            }
+        }
+
+        // Already under the threshold
+        assert_eq!(h.subdivide_until(6000), alloc::vec![h]);
+
+        // Can't split below a single pixel
+        let thin = make_hotspot(0, 0, 1, 4);
+        assert_eq!(thin.subdivide_until(0).len(), 4);
+    }
+
+    /// Checks the core overlap calculations give the same results regardless
+    /// of the precision feature.
+    ///
+    /// The boxes are written in `u16` units and scaled up to fill the larger
+    /// coordinate space under `high_precision`. Scaling both hotspots by the
+    /// same factor doesn't change any of these ratios, so the expected values
+    /// are shared by both precisions. Run under both feature sets to compare
+    /// them.
+    mod precision_matrix {
+        use super::*;
+
+        /// `u16::MAX * 65537 == u32::MAX`, so scaled boxes span the same
+        /// fraction of the coordinate space.
+        #[cfg(feature = "high_precision")]
+        const SCALE: CoordinateValue = 65537;
+        #[cfg(not(feature = "high_precision"))]
+        const SCALE: CoordinateValue = 1;
+
+        fn scaled((x1, y1, x2, y2): (u16, u16, u16, u16)) -> Hotspot<PixelRepr> {
+            Hotspot::builder().from_pixels((
+                Coordinate {
+                    x: x1 as CoordinateValue * SCALE,
+                    y: y1 as CoordinateValue * SCALE,
+                },
+                Coordinate {
+                    x: x2 as CoordinateValue * SCALE,
+                    y: y2 as CoordinateValue * SCALE,
+                },
+            ))
+        }
+
+        type Fraction = (u32, u32);
+
+        struct Case {
+            a: (u16, u16, u16, u16),
+            b: (u16, u16, u16, u16),
+            overlap: Fraction,
+            a_in_b: Fraction,
+            b_in_a: Fraction,
+            combined: (u16, u16, u16, u16),
+        }
+
+        const CASES: &[Case] = &[
+            // Half of each box overlaps
+            Case {
+                a: (0, 0, 10, 10),
+                b: (5, 0, 15, 10),
+                overlap: (1, 3),
+                a_in_b: (1, 2),
+                b_in_a: (1, 2),
+                combined: (0, 0, 15, 10),
+            },
+            // b is contained in a
+            Case {
+                a: (0, 0, 20, 20),
+                b: (5, 5, 15, 15),
+                overlap: (1, 4),
+                a_in_b: (1, 4),
+                b_in_a: (1, 1),
+                combined: (0, 0, 20, 20),
+            },
+            // Partial overlap which isn't a simple fraction
+            Case {
+                a: (1, 1, 4, 4),
+                b: (2, 2, 7, 7),
+                overlap: (2, 15),
+                a_in_b: (4, 9),
+                b_in_a: (4, 25),
+                combined: (1, 1, 7, 7),
+            },
+            // Disjoint
+            Case {
+                a: (0, 0, 10, 10),
+                b: (20, 20, 30, 30),
+                overlap: (0, 1),
+                a_in_b: (0, 1),
+                b_in_a: (0, 1),
+                combined: (0, 0, 30, 30),
+            },
+            // Only touching along an edge
+            Case {
+                a: (0, 0, 10, 10),
+                b: (10, 0, 20, 10),
+                overlap: (0, 1),
+                a_in_b: (0, 1),
+                b_in_a: (0, 1),
+                combined: (0, 0, 20, 10),
+            },
+            // Zero area
+            Case {
+                a: (5, 5, 5, 5),
+                b: (0, 0, 10, 10),
+                overlap: (0, 1),
+                a_in_b: (0, 1),
+                b_in_a: (0, 1),
+                combined: (0, 0, 10, 10),
+            },
+            // Spanning the whole coordinate space
+            Case {
+                a: (0, 0, u16::MAX, u16::MAX),
+                b: (0, 0, u16::MAX, u16::MAX / 3),
+                overlap: (1, 3),
+                a_in_b: (1, 3),
+                b_in_a: (1, 1),
+                combined: (0, 0, u16::MAX, u16::MAX),
+            },
+        ];
+
+        #[track_caller]
+        fn assert_fraction(actual: f32, (numerator, denominator): Fraction) {
+            let expected = numerator as f32 / denominator as f32;
+            assert!(
+                (actual - expected).abs() <= f32::EPSILON,
+                "expected {numerator}/{denominator} but got {actual}"
+            );
+        }
+
+        #[test]
+        fn test_overlap_matrix() {
+            for case in CASES {
+                let (a, b) = (scaled(case.a), scaled(case.b));
+                assert_fraction(a.overlap(&b), case.overlap);
+                assert_fraction(b.overlap(&a), case.overlap);
+                assert_fraction(a.overlap_in(&b), case.a_in_b);
+                assert_fraction(b.overlap_in(&a), case.b_in_a);
+
+                let max = if case.a_in_b.0 * case.b_in_a.1 >= case.b_in_a.0 * case.a_in_b.1 {
+                    case.a_in_b
+                } else {
+                    case.b_in_a
+                };
+                assert_fraction(a.max_overlap(&b), max);
+            }
+        }
+
+        #[test]
+        fn test_combine_hotspots_matrix() {
+            for case in CASES {
+                let (a, b) = (scaled(case.a), scaled(case.b));
+                assert_eq!(Hotspot::combine_hotspots(a, b), scaled(case.combined));
+                assert_eq!(Hotspot::combine_hotspots(b, a), scaled(case.combined));
+            }
+        }
+    }
+
     // Property-based tests (fuzzing)
     #[cfg(not(miri))]
     mod fuzz_tests {
@@ -902,7 +6002,38 @@ mod tests {
             }
         }
 
+        /// A straightforward implementation of [`Hotspot::overlap`], without
+        /// the early return for disjoint hotspots.
+        fn reference_overlap(a: &Hotspot, b: &Hotspot) -> f32 {
+            let area = |h: &Hotspot| {
+                (h.upper_right.x - h.lower_left.x) as InternalCalculationType
+                    * (h.upper_right.y - h.lower_left.y) as InternalCalculationType
+            };
+            let w = min!(a.upper_right.x, b.upper_right.x)
+                .saturating_sub(max!(a.lower_left.x, b.lower_left.x));
+            let h = min!(a.upper_right.y, b.upper_right.y)
+                .saturating_sub(max!(a.lower_left.y, b.lower_left.y));
+            let si = w as InternalCalculationType * h as InternalCalculationType;
+            let su = area(a) as f64 + area(b) as f64 - si as f64;
+            if su == 0.0 {
+                return 0.0;
+            }
+            (si as f64 / su) as f32
+        }
+
         proptest! {
+            #[test]
+            fn fuzz_overlap_matches_reference(h1 in arb_hotspot(), h2 in arb_hotspot()) {
+                prop_assert_eq!(h1.overlap(&h2).to_bits(), reference_overlap(&h1, &h2).to_bits());
+            }
+
+            #[test]
+            fn fuzz_overlap_ratios_bounded(h1 in arb_hotspot(), h2 in arb_hotspot()) {
+                for ratio in [h1.overlap(&h2), h1.overlap_in(&h2), h1.min_overlap(&h2)] {
+                    prop_assert!((0.0..=1.0).contains(&ratio));
+                }
+            }
+
             #[test]
             fn fuzz_from_pixels_invariants(c1 in arb_coordinate(), c2 in arb_coordinate()) {
                 let h = Hotspot::builder().from_pixels((c1, c2));
@@ -925,6 +6056,12 @@ mod tests {
                 prop_assert_eq!(h.upper_right.y, expected_top_y);
             }
 
+            #[cfg(not(feature = "high_precision"))]
+            #[test]
+            fn fuzz_pack_u64_roundtrip(h in arb_hotspot()) {
+                prop_assert_eq!(Hotspot::from_u64(h.pack_u64()), h);
+            }
+
             #[test]
             fn fuzz_overlap_symmetry(h1 in arb_hotspot(), h2 in arb_hotspot()) {
                 let o1 = h1.overlap(&h2);
@@ -967,13 +6104,11 @@ mod tests {
             fn fuzz_combine_hotspots_overlap_in(h1 in arb_hotspot(), h2 in arb_hotspot()) {
                 let combined = Hotspot::combine_hotspots(h1, h2);
 
-                let h1_area = (h1.upper_right.x - h1.lower_left.x) as InternalCalculationType * (h1.upper_right.y - h1.lower_left.y) as InternalCalculationType;
-                if h1_area > 0 {
+                if h1.area() > 0 {
                     prop_assert!((h1.overlap_in(&combined) - 1.0).abs() < 1e-5);
                 }
 
-                let h2_area = (h2.upper_right.x - h2.lower_left.x) as InternalCalculationType * (h2.upper_right.y - h2.lower_left.y) as InternalCalculationType;
-                if h2_area > 0 {
+                if h2.area() > 0 {
                     prop_assert!((h2.overlap_in(&combined) - 1.0).abs() < 1e-5);
                 }
             }
@@ -3,99 +3,161 @@ extern crate alloc;
 use alloc::{collections::BTreeSet, string::String, vec};
 use reflectapi::{Input, Output};
 
-use crate::{Coordinate, CoordinateValue, Hotspot};
+use crate::{Coordinate, CoordinateValue, Hotspot, ImageDimensions, repr::HotspotRepr};
 
+#[cfg(not(feature = "serde_objects"))]
 impl Input for Coordinate {
     fn reflectapi_input_type(schema: &mut reflectapi::Typespace) -> reflectapi::TypeReference {
         <(CoordinateValue, CoordinateValue) as Input>::reflectapi_input_type(schema)
     }
 }
 
+#[cfg(not(feature = "serde_objects"))]
 impl Output for Coordinate {
     fn reflectapi_output_type(schema: &mut reflectapi::Typespace) -> reflectapi::TypeReference {
         <(CoordinateValue, CoordinateValue) as Output>::reflectapi_output_type(schema)
     }
 }
 
-fn hotspot_type_def(coordinate_value_type: reflectapi::TypeReference) -> reflectapi::Type {
-    reflectapi::Type::Struct(reflectapi::Struct {
-        name: "Hotspot".into(),
+#[cfg(not(feature = "serde_objects"))]
+impl Input for ImageDimensions {
+    fn reflectapi_input_type(schema: &mut reflectapi::Typespace) -> reflectapi::TypeReference {
+        <(CoordinateValue, CoordinateValue) as Input>::reflectapi_input_type(schema)
+    }
+}
+
+#[cfg(not(feature = "serde_objects"))]
+impl Output for ImageDimensions {
+    fn reflectapi_output_type(schema: &mut reflectapi::Typespace) -> reflectapi::TypeReference {
+        <(CoordinateValue, CoordinateValue) as Output>::reflectapi_output_type(schema)
+    }
+}
+
+#[cfg(feature = "serde_objects")]
+impl Input for Coordinate {
+    fn reflectapi_input_type(schema: &mut reflectapi::Typespace) -> reflectapi::TypeReference {
+        let coordinate_value_type = <CoordinateValue as Input>::reflectapi_input_type(schema);
+        reserve_struct(schema, coordinate_def(coordinate_value_type))
+    }
+}
+
+#[cfg(feature = "serde_objects")]
+impl Output for Coordinate {
+    fn reflectapi_output_type(schema: &mut reflectapi::Typespace) -> reflectapi::TypeReference {
+        let coordinate_value_type = <CoordinateValue as Output>::reflectapi_output_type(schema);
+        reserve_struct(schema, coordinate_def(coordinate_value_type))
+    }
+}
+
+#[cfg(feature = "serde_objects")]
+impl Input for ImageDimensions {
+    fn reflectapi_input_type(schema: &mut reflectapi::Typespace) -> reflectapi::TypeReference {
+        let coordinate_value_type = <CoordinateValue as Input>::reflectapi_input_type(schema);
+        reserve_struct(schema, image_dimensions_def(coordinate_value_type))
+    }
+}
+
+#[cfg(feature = "serde_objects")]
+impl Output for ImageDimensions {
+    fn reflectapi_output_type(schema: &mut reflectapi::Typespace) -> reflectapi::TypeReference {
+        let coordinate_value_type = <CoordinateValue as Output>::reflectapi_output_type(schema);
+        reserve_struct(schema, image_dimensions_def(coordinate_value_type))
+    }
+}
+
+/// Insert the provided struct definition into the schema if it isn't already
+/// present, returning a reference to it.
+fn reserve_struct(
+    schema: &mut reflectapi::Typespace,
+    definition: reflectapi::Struct,
+) -> reflectapi::TypeReference {
+    let name = definition.name.clone();
+    if schema.reserve_type(&name) {
+        schema.insert_type(reflectapi::Type::Struct(definition));
+    }
+    reflectapi::TypeReference::new(name, vec![])
+}
+
+/// Build a struct definition where every field has the same type.
+fn struct_def(
+    name: &str,
+    description: &str,
+    field_names: &[&str],
+    field_type: reflectapi::TypeReference,
+) -> reflectapi::Struct {
+    reflectapi::Struct {
+        name: name.into(),
         serde_name: "".into(),
-        description: "A rectangular hotspot represented as a rectangle with two corners.".into(),
+        description: description.into(),
         parameters: vec![],
-        fields: reflectapi::Fields::Named(vec![
-            reflectapi::Field {
-                name: "x1".into(),
-                serde_name: "".into(),
-                description: "".into(),
-                deprecation_note: None,
-                type_ref: coordinate_value_type.clone(),
-                required: true,
-                flattened: false,
-                transform_callback: String::new(),
-                transform_callback_fn: None,
-            },
-            reflectapi::Field {
-                name: "y1".into(),
-                serde_name: "".into(),
-                description: "".into(),
-                deprecation_note: None,
-                type_ref: coordinate_value_type.clone(),
-                required: true,
-                flattened: false,
-                transform_callback: String::new(),
-                transform_callback_fn: None,
-            },
-            reflectapi::Field {
-                name: "x2".into(),
-                serde_name: "".into(),
-                description: "".into(),
-                deprecation_note: None,
-                type_ref: coordinate_value_type.clone(),
-                required: true,
-                flattened: false,
-                transform_callback: String::new(),
-                transform_callback_fn: None,
-            },
-            reflectapi::Field {
-                name: "y2".into(),
-                serde_name: "".into(),
-                description: "".into(),
-                deprecation_note: None,
-                type_ref: coordinate_value_type,
-                required: true,
-                flattened: false,
-                transform_callback: String::new(),
-                transform_callback_fn: None,
-            },
-        ]),
+        fields: reflectapi::Fields::Named(
+            field_names
+                .iter()
+                .map(|field_name| reflectapi::Field {
+                    name: (*field_name).into(),
+                    serde_name: "".into(),
+                    description: "".into(),
+                    deprecation_note: None,
+                    type_ref: field_type.clone(),
+                    required: true,
+                    flattened: false,
+                    transform_callback: String::new(),
+                    transform_callback_fn: None,
+                })
+                .collect(),
+        ),
         transparent: false,
         codegen_config: reflectapi::LanguageSpecificTypeCodegenConfig {
             rust: reflectapi::RustTypeCodegenConfig {
                 additional_derives: BTreeSet::new(),
             },
         },
-    })
+    }
+}
+
+#[cfg(feature = "serde_objects")]
+fn coordinate_def(coordinate_value_type: reflectapi::TypeReference) -> reflectapi::Struct {
+    struct_def(
+        "Coordinate",
+        "A coordinate in 2 Dimensional space.",
+        &["x", "y"],
+        coordinate_value_type,
+    )
+}
+
+#[cfg(feature = "serde_objects")]
+fn image_dimensions_def(coordinate_value_type: reflectapi::TypeReference) -> reflectapi::Struct {
+    struct_def(
+        "ImageDimensions",
+        "The dimensions of an image.",
+        &["width", "height"],
+        coordinate_value_type,
+    )
+}
+
+/// The hotspot is named after its representation's serde struct name, so
+/// pixel and percentage hotspots are distinct types in the schema.
+fn hotspot_type_def<R: HotspotRepr>(
+    coordinate_value_type: reflectapi::TypeReference,
+) -> reflectapi::Struct {
+    struct_def(
+        R::STRUCT_NAME,
+        "A rectangular hotspot represented as a rectangle with two corners.",
+        &["x1", "y1", "x2", "y2"],
+        coordinate_value_type,
+    )
 }
 
-impl<R> Input for Hotspot<R> {
+impl<R: HotspotRepr> Input for Hotspot<R> {
     fn reflectapi_input_type(schema: &mut reflectapi::Typespace) -> reflectapi::TypeReference {
-        let resolved_type_name = "Hotspot";
         let coordinate_value_type = <CoordinateValue as Input>::reflectapi_input_type(schema);
-        if schema.reserve_type(resolved_type_name.as_ref()) {
-            schema.insert_type(hotspot_type_def(coordinate_value_type));
-        }
-        reflectapi::TypeReference::new(resolved_type_name, vec![])
+        reserve_struct(schema, hotspot_type_def::<R>(coordinate_value_type))
     }
 }
 
-impl<R> Output for Hotspot<R> {
+impl<R: HotspotRepr> Output for Hotspot<R> {
     fn reflectapi_output_type(schema: &mut reflectapi::Typespace) -> reflectapi::TypeReference {
-        let resolved_type_name = "Hotspot";
         let coordinate_value_type = <CoordinateValue as Output>::reflectapi_output_type(schema);
-        if schema.reserve_type(resolved_type_name.as_ref()) {
-            schema.insert_type(hotspot_type_def(coordinate_value_type));
-        }
-        reflectapi::TypeReference::new(resolved_type_name, vec![])
+        reserve_struct(schema, hotspot_type_def::<R>(coordinate_value_type))
     }
 }
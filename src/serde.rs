@@ -5,21 +5,103 @@
 use serde::{
     Deserialize,
     de::{self, Visitor},
-    ser::{SerializeStruct, SerializeTupleStruct},
+    ser::SerializeStruct,
 };
 
+#[cfg(not(feature = "serde_objects"))]
+use serde::ser::SerializeTupleStruct;
+
 use crate::{Coordinate, CoordinateValue, Hotspot, ImageDimensions, repr::HotspotRepr};
 
+/// The field names used for [`Coordinate`] in the object format.
+const COORDINATE_FIELDS: &[&str; 2] = &["x", "y"];
+
+/// The field names used for [`ImageDimensions`] in the object format.
+const IMAGE_DIMENSIONS_FIELDS: &[&str; 2] = &["width", "height"];
+
+/// Identifies a field by its index in a list of field names.
+#[derive(Clone, Copy)]
+struct FieldSeed(&'static [&'static str]);
+
+impl<'de> de::DeserializeSeed<'de> for FieldSeed {
+    type Value = usize;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FieldSeed {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a field name")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.0
+            .iter()
+            .position(|field| *field == v)
+            .ok_or_else(|| de::Error::unknown_field(v, self.0))
+    }
+}
+
+/// Deserialize the two values of a pair-like struct from a map with the
+/// provided field names.
+fn visit_pair_map<'de, A>(
+    mut map: A,
+    fields: &'static [&'static str; 2],
+) -> Result<(CoordinateValue, CoordinateValue), A::Error>
+where
+    A: de::MapAccess<'de>,
+{
+    let mut values: [Option<CoordinateValue>; 2] = [None, None];
+
+    while let Some(index) = map.next_key_seed(FieldSeed(fields))? {
+        let (Some(value), Some(field)) = (values.get_mut(index), fields.get(index)) else {
+            return Err(de::Error::custom("field index out of range"));
+        };
+        if value.is_some() {
+            return Err(de::Error::duplicate_field(field));
+        }
+        *value = Some(map.next_value()?);
+    }
+
+    let [first, second] = values;
+    let first = first.ok_or_else(|| de::Error::missing_field(fields[0]))?;
+    let second = second.ok_or_else(|| de::Error::missing_field(fields[1]))?;
+
+    Ok((first, second))
+}
+
 impl serde::Serialize for ImageDimensions {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         let Self { height, width } = &self;
-        let mut ser = serializer.serialize_tuple_struct("ImageDimensions", 2)?;
-        ser.serialize_field(width)?;
-        ser.serialize_field(height)?;
-        ser.end()
+
+        #[cfg(not(feature = "serde_objects"))]
+        {
+            let mut ser = serializer.serialize_tuple_struct("ImageDimensions", 2)?;
+            ser.serialize_field(width)?;
+            ser.serialize_field(height)?;
+            ser.end()
+        }
+
+        #[cfg(feature = "serde_objects")]
+        {
+            let mut ser = serializer.serialize_struct("ImageDimensions", 2)?;
+            ser.serialize_field("width", width)?;
+            ser.serialize_field("height", height)?;
+            ser.end()
+        }
     }
 }
 
@@ -34,7 +116,7 @@ impl<'de> serde::Deserialize<'de> for ImageDimensions {
             type Value = ImageDimensions;
 
             fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
-                formatter.write_str("a dimension as [x, y]")
+                formatter.write_str("a dimension as either [width, height] or {width, height}")
             }
 
             fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
@@ -49,9 +131,29 @@ impl<'de> serde::Deserialize<'de> for ImageDimensions {
                     .ok_or_else(|| de::Error::invalid_length(1, &self))?;
                 Ok(ImageDimensions { width, height })
             }
+
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                let (width, height) = visit_pair_map(map, IMAGE_DIMENSIONS_FIELDS)?;
+                Ok(ImageDimensions { width, height })
+            }
         }
 
-        deserializer.deserialize_tuple_struct("ImageDimensions", 2, ImageDimensionsVisitor)
+        #[cfg(not(feature = "serde_objects"))]
+        {
+            deserializer.deserialize_tuple_struct("ImageDimensions", 2, ImageDimensionsVisitor)
+        }
+
+        #[cfg(feature = "serde_objects")]
+        {
+            deserializer.deserialize_struct(
+                "ImageDimensions",
+                IMAGE_DIMENSIONS_FIELDS,
+                ImageDimensionsVisitor,
+            )
+        }
     }
 }
 
@@ -61,10 +163,22 @@ impl serde::Serialize for Coordinate {
         S: serde::Serializer,
     {
         let Self { x, y } = &self;
-        let mut ser = serializer.serialize_tuple_struct("Coordinate", 2)?;
-        ser.serialize_field(x)?;
-        ser.serialize_field(y)?;
-        ser.end()
+
+        #[cfg(not(feature = "serde_objects"))]
+        {
+            let mut ser = serializer.serialize_tuple_struct("Coordinate", 2)?;
+            ser.serialize_field(x)?;
+            ser.serialize_field(y)?;
+            ser.end()
+        }
+
+        #[cfg(feature = "serde_objects")]
+        {
+            let mut ser = serializer.serialize_struct("Coordinate", 2)?;
+            ser.serialize_field("x", x)?;
+            ser.serialize_field("y", y)?;
+            ser.end()
+        }
     }
 }
 
@@ -79,7 +193,7 @@ impl<'de> serde::Deserialize<'de> for Coordinate {
             type Value = Coordinate;
 
             fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
-                formatter.write_str("a coordinate as either [x, y]")
+                formatter.write_str("a coordinate as either [x, y] or {x, y}")
             }
 
             fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
@@ -94,9 +208,25 @@ impl<'de> serde::Deserialize<'de> for Coordinate {
                     .ok_or_else(|| de::Error::invalid_length(1, &self))?;
                 Ok(Coordinate { x, y })
             }
+
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                let (x, y) = visit_pair_map(map, COORDINATE_FIELDS)?;
+                Ok(Coordinate { x, y })
+            }
+        }
+
+        #[cfg(not(feature = "serde_objects"))]
+        {
+            deserializer.deserialize_tuple_struct("Coordinate", 2, CoordinateVisitor)
         }
 
-        deserializer.deserialize_tuple_struct("Coordinate", 2, CoordinateVisitor)
+        #[cfg(feature = "serde_objects")]
+        {
+            deserializer.deserialize_struct("Coordinate", COORDINATE_FIELDS, CoordinateVisitor)
+        }
     }
 }
 
@@ -186,20 +316,27 @@ impl<'de, R: HotspotRepr> serde::Deserialize<'de> for Hotspot<R> {
             where
                 A: de::SeqAccess<'de>,
             {
-                let x1 = seq
+                let x1: CoordinateValue = seq
                     .next_element()?
                     .ok_or_else(|| de::Error::invalid_length(0, &self))?;
-                let y1 = seq
+                let y1: CoordinateValue = seq
                     .next_element()?
                     .ok_or_else(|| de::Error::invalid_length(1, &self))?;
-                let x2 = seq
+                let x2: CoordinateValue = seq
                     .next_element()?
                     .ok_or_else(|| de::Error::invalid_length(2, &self))?;
-                let y2 = seq
+                let y2: CoordinateValue = seq
                     .next_element()?
                     .ok_or_else(|| de::Error::invalid_length(3, &self))?;
 
-                Ok(HotspotFields { x1, y1, x2, y2 })
+                // Flat arrays come from a variety of producers, so normalize the
+                // corners in the same way as `HotspotBuilder::from_pixels`.
+                Ok(HotspotFields {
+                    x1: x1.max(x2),
+                    y1: y1.max(y2),
+                    x2: x1.min(x2),
+                    y2: y1.min(y2),
+                })
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
@@ -268,6 +405,24 @@ impl<'de, R: HotspotRepr> serde::Deserialize<'de> for Hotspot<R> {
     }
 }
 
+#[cfg(feature = "serde_json")]
+impl TryFrom<&serde_json::Value> for Coordinate {
+    type Error = serde_json::Error;
+
+    fn try_from(value: &serde_json::Value) -> Result<Self, Self::Error> {
+        Self::deserialize(value)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<R: HotspotRepr> TryFrom<&serde_json::Value> for Hotspot<R> {
+    type Error = serde_json::Error;
+
+    fn try_from(value: &serde_json::Value) -> Result<Self, Self::Error> {
+        Self::deserialize(value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     extern crate alloc;
@@ -295,6 +450,7 @@ mod tests {
     // Coordinate Serialization Tests
     // ============================================================================
 
+    #[cfg(not(feature = "serde_objects"))]
     #[test]
     fn test_coordinate_serialize_deserialize() {
         let coord = Coordinate { x: 100, y: 200 };
@@ -313,6 +469,7 @@ mod tests {
         assert_eq!(coord, Coordinate { x: 100, y: 200 });
     }
 
+    #[cfg(not(feature = "serde_objects"))]
     #[test]
     fn test_coordinate_zero_values() {
         let coord = Coordinate { x: 0, y: 0 };
@@ -452,6 +609,19 @@ mod tests {
         assert_eq!(h2, h3);
     }
 
+    #[test]
+    fn test_hotspot_deserialize_from_array_normalizes_corners() {
+        let hotspot: Hotspot<PixelRepr> = serde_json::from_str("[100,200,50,75]").unwrap();
+        assert_eq!(hotspot.upper_right, Coordinate { x: 100, y: 200 });
+        assert_eq!(hotspot.lower_left, Coordinate { x: 50, y: 75 });
+
+        let reversed: Hotspot<PixelRepr> = serde_json::from_str("[50,75,100,200]").unwrap();
+        assert_eq!(reversed, hotspot);
+
+        let mixed: Hotspot<PixelRepr> = serde_json::from_str("[50,200,100,75]").unwrap();
+        assert_eq!(mixed, hotspot);
+    }
+
     // ============================================================================
     // Hotspot Error Handling Tests
     // ============================================================================
@@ -583,6 +753,7 @@ mod tests {
         assert_eq!(hotspot.lower_left, Coordinate { x: 30, y: 40 });
     }
 
+    #[cfg(not(feature = "serde_objects"))]
     #[test]
     fn test_coordinate_to_value() {
         let coord = Coordinate { x: 777, y: 888 };
@@ -600,4 +771,105 @@ mod tests {
         assert_eq!(value["x2"], 1);
         assert_eq!(value["y2"], 2);
     }
+
+    // ============================================================================
+    // serde_json::Value Conversion Tests
+    // ============================================================================
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_coordinate_try_from_value() {
+        let value = serde_json::json!([500, 600]);
+        let coord = Coordinate::try_from(&value).unwrap();
+        assert_eq!(coord, Coordinate { x: 500, y: 600 });
+
+        let value = serde_json::json!([500]);
+        assert!(Coordinate::try_from(&value).is_err());
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_hotspot_try_from_value() {
+        let object = serde_json::json!({"x1": 30, "y1": 40, "x2": 10, "y2": 20});
+        let hotspot = Hotspot::<PixelRepr>::try_from(&object).unwrap();
+        assert_eq!(hotspot, make_hotspot(10, 20, 30, 40));
+
+        let array = serde_json::json!([30, 40, 10, 20]);
+        let hotspot = Hotspot::<PixelRepr>::try_from(&array).unwrap();
+        assert_eq!(hotspot, make_hotspot(10, 20, 30, 40));
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_hotspot_try_from_malformed_value() {
+        let missing = serde_json::json!({"x1": 30, "y1": 40, "x2": 10});
+        let error = Hotspot::<PixelRepr>::try_from(&missing).unwrap_err();
+        assert!(error.to_string().contains("y2"));
+
+        let wrong_type = serde_json::json!("not a hotspot");
+        assert!(Hotspot::<PixelRepr>::try_from(&wrong_type).is_err());
+    }
+
+    // ============================================================================
+    // Object Format Tests
+    // ============================================================================
+
+    #[cfg(not(feature = "serde_objects"))]
+    #[test]
+    fn test_image_dimensions_tuple_format() {
+        let dims = ImageDimensions {
+            width: 1920,
+            height: 1080,
+        };
+        let json = serde_json::to_string(&dims).unwrap();
+        assert_eq!(json, "[1920,1080]");
+
+        let deserialized: ImageDimensions = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, dims);
+    }
+
+    #[cfg(feature = "serde_objects")]
+    #[test]
+    fn test_coordinate_object_format() {
+        let coord = Coordinate { x: 100, y: 200 };
+        let json = serde_json::to_string(&coord).unwrap();
+        assert_eq!(json, r#"{"x":100,"y":200}"#);
+
+        // Both the object and tuple formats are accepted
+        let deserialized: Coordinate = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, coord);
+        let deserialized: Coordinate = serde_json::from_str("[100,200]").unwrap();
+        assert_eq!(deserialized, coord);
+        let deserialized: Coordinate = serde_json::from_str(r#"{"y":200,"x":100}"#).unwrap();
+        assert_eq!(deserialized, coord);
+    }
+
+    #[cfg(feature = "serde_objects")]
+    #[test]
+    fn test_image_dimensions_object_format() {
+        let dims = ImageDimensions {
+            width: 1920,
+            height: 1080,
+        };
+        let json = serde_json::to_string(&dims).unwrap();
+        assert_eq!(json, r#"{"width":1920,"height":1080}"#);
+
+        let deserialized: ImageDimensions = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, dims);
+        let deserialized: ImageDimensions = serde_json::from_str("[1920,1080]").unwrap();
+        assert_eq!(deserialized, dims);
+    }
+
+    #[cfg(feature = "serde_objects")]
+    #[test]
+    fn test_object_format_errors() {
+        let result: Result<Coordinate, _> = serde_json::from_str(r#"{"x":1,"x":2,"y":3}"#);
+        assert!(result.unwrap_err().to_string().contains("duplicate"));
+
+        let result: Result<Coordinate, _> = serde_json::from_str(r#"{"x":1,"z":2}"#);
+        assert!(result.unwrap_err().to_string().contains("unknown field"));
+
+        let result: Result<ImageDimensions, _> = serde_json::from_str(r#"{"width":1}"#);
+        assert!(result.unwrap_err().to_string().contains("height"));
+    }
 }
@@ -48,9 +48,9 @@ mod internal {
             /// Echoes back the provided hotspot
             pub async fn echo_hotspot(
                 &self,
-                input: super::types::Hotspot,
+                input: super::types::HotspotPx,
                 headers: reflectapi::Empty,
-            ) -> Result<super::types::Hotspot, reflectapi::rt::Error<reflectapi::Empty, C::Error>>
+            ) -> Result<super::types::HotspotPx, reflectapi::rt::Error<reflectapi::Empty, C::Error>>
             {
                 reflectapi::rt::__request_impl(
                     &self.client,
@@ -62,13 +62,39 @@ mod internal {
                 )
                 .await
             }
+            /// Converts a pixel hotspot to a percentage hotspot for the provided image dimensions
+            pub async fn pixels_to_percentage(
+                &self,
+                input: (super::types::HotspotPx, (u32, u32)),
+                headers: reflectapi::Empty,
+            ) -> Result<super::types::HotspotRel, reflectapi::rt::Error<reflectapi::Empty, C::Error>>
+            {
+                reflectapi::rt::__request_impl(
+                    &self.client,
+                    self.base_url
+                        .join("/pixels_to_percentage")
+                        .expect("checked base_url already and path is valid"),
+                    input,
+                    headers,
+                )
+                .await
+            }
         }
     }
     pub mod types {
 
         /// A rectangular hotspot represented as a rectangle with two corners.
         #[derive(Debug, serde::Deserialize, serde::Serialize)]
-        pub struct Hotspot {
+        pub struct HotspotPx {
+            pub x1: u32,
+            pub y1: u32,
+            pub x2: u32,
+            pub y2: u32,
+        }
+
+        /// A rectangular hotspot represented as a rectangle with two corners.
+        #[derive(Debug, serde::Deserialize)]
+        pub struct HotspotRel {
             pub x1: u32,
             pub y1: u32,
             pub x2: u32,
@@ -0,0 +1,120 @@
+#[cfg(feature = "reflectapi")]
+mod internal {
+    // DO NOT MODIFY THIS FILE MANUALLY
+    // This file was generated by reflectapi-cli
+    //
+    // Schema name: Hotspots Test API
+    // Test API for validating reflectapi serialization
+
+    pub use interface::Interface;
+    pub use reflectapi::rt::*;
+
+    pub mod interface {
+
+        #[derive(Debug)]
+        pub struct Interface<C: reflectapi::rt::Client + Clone> {
+            client: C,
+            base_url: reflectapi::rt::Url,
+        }
+
+        impl<C: reflectapi::rt::Client + Clone> Interface<C> {
+            pub fn try_new(
+                client: C,
+                base_url: reflectapi::rt::Url,
+            ) -> std::result::Result<Self, reflectapi::rt::UrlParseError> {
+                if base_url.cannot_be_a_base() {
+                    return Err(reflectapi::rt::UrlParseError::RelativeUrlWithCannotBeABaseBase);
+                }
+
+                Ok(Self { client, base_url })
+            }
+            /// Echoes back the provided coordinate
+            pub async fn echo_coordinate(
+                &self,
+                input: super::types::Coordinate,
+                headers: reflectapi::Empty,
+            ) -> Result<super::types::Coordinate, reflectapi::rt::Error<reflectapi::Empty, C::Error>>
+            {
+                reflectapi::rt::__request_impl(
+                    &self.client,
+                    self.base_url
+                        .join("/echo_coordinate")
+                        .expect("checked base_url already and path is valid"),
+                    input,
+                    headers,
+                )
+                .await
+            }
+            /// Echoes back the provided hotspot
+            pub async fn echo_hotspot(
+                &self,
+                input: super::types::HotspotPx,
+                headers: reflectapi::Empty,
+            ) -> Result<super::types::HotspotPx, reflectapi::rt::Error<reflectapi::Empty, C::Error>>
+            {
+                reflectapi::rt::__request_impl(
+                    &self.client,
+                    self.base_url
+                        .join("/echo_hotspot")
+                        .expect("checked base_url already and path is valid"),
+                    input,
+                    headers,
+                )
+                .await
+            }
+            /// Converts a pixel hotspot to a percentage hotspot for the provided image dimensions
+            pub async fn pixels_to_percentage(
+                &self,
+                input: (super::types::HotspotPx, super::types::ImageDimensions),
+                headers: reflectapi::Empty,
+            ) -> Result<super::types::HotspotRel, reflectapi::rt::Error<reflectapi::Empty, C::Error>>
+            {
+                reflectapi::rt::__request_impl(
+                    &self.client,
+                    self.base_url
+                        .join("/pixels_to_percentage")
+                        .expect("checked base_url already and path is valid"),
+                    input,
+                    headers,
+                )
+                .await
+            }
+        }
+    }
+    pub mod types {
+
+        /// A coordinate in 2 Dimensional space.
+        #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        pub struct Coordinate {
+            pub x: u32,
+            pub y: u32,
+        }
+
+        /// A rectangular hotspot represented as a rectangle with two corners.
+        #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        pub struct HotspotPx {
+            pub x1: u32,
+            pub y1: u32,
+            pub x2: u32,
+            pub y2: u32,
+        }
+
+        /// A rectangular hotspot represented as a rectangle with two corners.
+        #[derive(Debug, serde::Deserialize)]
+        pub struct HotspotRel {
+            pub x1: u32,
+            pub y1: u32,
+            pub x2: u32,
+            pub y2: u32,
+        }
+
+        /// The dimensions of an image.
+        #[derive(Debug, serde::Serialize)]
+        pub struct ImageDimensions {
+            pub width: u32,
+            pub height: u32,
+        }
+    }
+}
+#[cfg(feature = "reflectapi")]
+pub use internal::*;
@@ -2,11 +2,22 @@
 
 use std::{io::Write as _, time::Duration};
 
-use hotspots::{Coordinate, Hotspot, repr::PixelRepr};
+use hotspots::{
+    Coordinate, Hotspot, ImageDimensions,
+    repr::{PercentageRepr, PixelRepr},
+};
 use reflectapi::codegen::rust::Config;
 use tokio::time::timeout;
 
+/// The file the generated client is written to, which depends on the wire
+/// format selected by `serde_objects`.
+#[cfg(not(feature = "serde_objects"))]
+const GENERATED_CLIENT: &str = "generated_client.rs";
+#[cfg(feature = "serde_objects")]
+const GENERATED_CLIENT: &str = "generated_client_serde_objects.rs";
+
 // Include the generated client
+#[cfg(not(feature = "serde_objects"))]
 #[allow(dead_code, unused_imports, clippy::all)]
 mod generated_client {
     include!(concat!(
@@ -14,6 +25,56 @@ mod generated_client {
         "/tests/generated_client.rs"
     ));
 }
+#[cfg(feature = "serde_objects")]
+#[allow(dead_code, unused_imports, clippy::all)]
+mod generated_client {
+    include!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/generated_client_serde_objects.rs"
+    ));
+}
+
+/// Build a coordinate in the wire format of the generated client.
+#[cfg(not(feature = "serde_objects"))]
+fn client_coordinate(x: u32, y: u32) -> (u32, u32) {
+    (x, y)
+}
+#[cfg(feature = "serde_objects")]
+fn client_coordinate(x: u32, y: u32) -> generated_client::types::Coordinate {
+    generated_client::types::Coordinate { x, y }
+}
+
+/// Read a coordinate in the wire format of the generated client.
+#[cfg(not(feature = "serde_objects"))]
+fn coordinate_xy(coordinate: (u32, u32)) -> (u32, u32) {
+    coordinate
+}
+#[cfg(feature = "serde_objects")]
+fn coordinate_xy(coordinate: generated_client::types::Coordinate) -> (u32, u32) {
+    (coordinate.x, coordinate.y)
+}
+
+/// Build image dimensions in the wire format of the generated client.
+#[cfg(not(feature = "serde_objects"))]
+fn client_dimensions(ImageDimensions { width, height }: ImageDimensions) -> (u32, u32) {
+    (width, height)
+}
+#[cfg(feature = "serde_objects")]
+fn client_dimensions(
+    ImageDimensions { width, height }: ImageDimensions,
+) -> generated_client::types::ImageDimensions {
+    generated_client::types::ImageDimensions { width, height }
+}
+
+/// Snapshot settings for the wire format selected by `serde_objects`, which
+/// keeps a separate set of snapshots.
+fn snapshot_settings() -> insta::Settings {
+    #[allow(unused_mut)]
+    let mut settings = insta::Settings::clone_current();
+    #[cfg(feature = "serde_objects")]
+    settings.set_snapshot_suffix("serde_objects");
+    settings
+}
 
 /// HTTP client implementation for the generated client
 struct TestHttpClient {
@@ -69,6 +130,14 @@ fn build_test_api() -> (reflectapi::Schema, Vec<reflectapi::Router<()>>) {
         request
     }
 
+    async fn pixels_to_percentage(
+        _: (),
+        (hotspot, dimensions): (Hotspot<PixelRepr>, ImageDimensions),
+        _headers: reflectapi::Empty,
+    ) -> Hotspot<PercentageRepr> {
+        Hotspot::as_percentage(hotspot, dimensions)
+    }
+
     reflectapi::Builder::new()
         .name("Hotspots Test API")
         .description("Test API for validating reflectapi serialization")
@@ -80,6 +149,11 @@ fn build_test_api() -> (reflectapi::Schema, Vec<reflectapi::Router<()>>) {
             b.name("echo_hotspot")
                 .description("Echoes back the provided hotspot")
         })
+        .route(pixels_to_percentage, |b| {
+            b.name("pixels_to_percentage").description(
+                "Converts a pixel hotspot to a percentage hotspot for the provided image dimensions",
+            )
+        })
         .build()
         .expect("Failed to build reflectapi schema")
 }
@@ -128,14 +202,13 @@ fn test_snapshot_rust_client() {
 
     let client_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
         .join("tests")
-        .join("generated_client.rs");
+        .join(GENERATED_CLIENT);
 
-    let mut file =
-        std::fs::File::create(&client_path).expect("Failed to create generated_client.rs");
+    let mut file = std::fs::File::create(&client_path).expect("Failed to create generated client");
     file.write_all(generated_for_file.as_bytes())
         .expect("Failed to write generated client");
 
-    insta::assert_snapshot!("rust_client", generated);
+    snapshot_settings().bind(|| insta::assert_snapshot!("rust_client", generated));
 }
 
 #[test]
@@ -146,7 +219,7 @@ fn test_snapshot_openapi_spec() {
     let spec_json =
         serde_json::to_string_pretty(&openapi_spec).expect("Failed to serialize OpenAPI spec");
 
-    insta::assert_snapshot!("openapi_spec", spec_json);
+    snapshot_settings().bind(|| insta::assert_snapshot!("openapi_spec", spec_json));
 }
 
 async fn spawn_test_server() -> (tokio::task::JoinHandle<()>, String) {
@@ -190,15 +263,14 @@ async fn test_echo_coordinate() {
             .expect("Failed to create generated client");
 
         // Test using generated client
-        let test_coord = (10, 20);
+        let test_coord = client_coordinate(10, 20);
 
         let echoed = client
             .echo_coordinate(test_coord, reflectapi::Empty {})
             .await
             .expect("Failed to call echo_coordinate via generated client");
 
-        assert_eq!(echoed.0, 10);
-        assert_eq!(echoed.1, 20);
+        assert_eq!(coordinate_xy(echoed), (10, 20));
     })
     .await;
 
@@ -226,7 +298,7 @@ async fn test_echo_hotspot() {
             .expect("Failed to create generated client");
 
         // Test using generated client and generated types
-        let test_hotspot = generated_client::types::Hotspot {
+        let test_hotspot = generated_client::types::HotspotPx {
             x1: 50,
             y1: 60,
             x2: 150,
@@ -270,7 +342,7 @@ async fn test_generated_client_integration() {
             .expect("Failed to create generated client");
 
         // Test 1: Echo hotspot using generated client
-        let test_hotspot = generated_client::types::Hotspot {
+        let test_hotspot = generated_client::types::HotspotPx {
             x1: 100,
             y1: 200,
             x2: 300,
@@ -290,15 +362,14 @@ async fn test_generated_client_integration() {
         println!("✓ Generated client successfully called echo_hotspot");
 
         // Test 2: Echo coordinate using generated client
-        let test_coord = (150, 250);
+        let test_coord = client_coordinate(150, 250);
 
         let echoed_coord = client
             .echo_coordinate(test_coord, reflectapi::Empty {})
             .await
             .expect("Failed to call echo_coordinate via generated client");
 
-        assert_eq!(echoed_coord.0, 150);
-        assert_eq!(echoed_coord.1, 250);
+        assert_eq!(coordinate_xy(echoed_coord), (150, 250));
 
         println!("✓ Generated client successfully called echo_coordinate");
     })
@@ -311,3 +382,65 @@ async fn test_generated_client_integration() {
         "Test timed out - generated client may not be working correctly"
     );
 }
+
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(not(feature = "reflectapi"), ignore = "reflectapi feature not enabled")]
+async fn test_pixels_to_percentage() {
+    let (server_handle, base_url) = spawn_test_server().await;
+
+    let test_result = timeout(Duration::from_secs(5), async {
+        // Create the generated client
+        let http_client = TestHttpClient {
+            client: reqwest::Client::new(),
+        };
+
+        let base_url = reflectapi::rt::Url::parse(&base_url).expect("Failed to parse base URL");
+        let client = generated_client::Interface::try_new(http_client, base_url)
+            .expect("Failed to create generated client");
+
+        let dimensions = ImageDimensions {
+            width: 1000,
+            height: 800,
+        };
+        let pixels = Hotspot::builder()
+            .from_pixels((Coordinate { x: 50, y: 60 }, Coordinate { x: 150, y: 160 }));
+
+        let test_hotspot = generated_client::types::HotspotPx {
+            x1: 150,
+            y1: 160,
+            x2: 50,
+            y2: 60,
+        };
+
+        let converted: generated_client::types::HotspotRel = client
+            .pixels_to_percentage(
+                (test_hotspot, client_dimensions(dimensions)),
+                reflectapi::Empty {},
+            )
+            .await
+            .expect("Failed to call pixels_to_percentage via generated client");
+
+        // The percentage representation is not the same as the pixel representation on the wire
+        assert_ne!(converted.x1, 150);
+        assert_ne!(converted.y1, 160);
+
+        let converted: Hotspot<PercentageRepr> = serde_json::from_value(serde_json::json!({
+            "x1": converted.x1,
+            "y1": converted.y1,
+            "x2": converted.x2,
+            "y2": converted.y2,
+        }))
+        .expect("Failed to deserialize converted hotspot");
+
+        assert_eq!(converted, Hotspot::as_percentage(pixels, dimensions));
+        assert_eq!(Hotspot::as_pixels(converted, dimensions), pixels);
+    })
+    .await;
+
+    assert!(
+        test_result.is_ok(),
+        "Test timed out - server may not be responding"
+    );
+
+    server_handle.abort();
+}